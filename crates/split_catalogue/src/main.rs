@@ -0,0 +1,351 @@
+//! Generates `src/split_catalogue.json` from the source of `src/splits.rs` and `src/hollow_knight_memory.rs`,
+//! with the key, description, tooltip, and region of every `Split` variant,
+//! which of the split functions evaluate it, and which PlayerData fields it reads.
+//!
+//! Run `make split_catalogue` from the root of the repository after changing splits.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use proc_macro2::{TokenStream, TokenTree};
+use serde::Serialize;
+use syn::visit::Visit;
+use syn::{Attribute, Block, Expr, ExprLit, File, ImplItem, Item, Lit, Member, Meta, Pat, Path, Stmt, Type};
+
+const SRC: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../src");
+
+/// The functions in splits.rs that evaluate splits, and the evaluation kind of each
+const EVALUATIONS: [(&str, &str); 3] = [
+    ("transition_splits", "transition"),
+    ("transition_once_splits", "transition_once"),
+    ("continuous_splits", "continuous"),
+];
+
+#[derive(Serialize)]
+struct SplitEntry {
+    key: String,
+    description: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    tooltip: String,
+    region: String,
+    evaluation: Vec<&'static str>,
+    player_data: BTreeSet<String>,
+}
+
+/// The receivers of method calls that can lead to PlayerData fields
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+enum Receiver {
+    Splits,
+    GameManagerFinder,
+    PlayerDataStore,
+}
+
+/// The function bodies that a split can reach, and the PlayerData paths of the pointers
+struct Sources<'a> {
+    splits: BTreeMap<String, &'a Block>,
+    game_manager_finder: BTreeMap<String, &'a Block>,
+    player_data_store: BTreeMap<String, &'a Block>,
+    player_data_paths: BTreeMap<String, String>,
+}
+
+fn main() {
+    let splits_rs = read_src("splits.rs");
+    let memory_rs = read_src("hollow_knight_memory.rs");
+    let splits_file = parse_src("splits.rs", &splits_rs);
+    let memory_file = parse_src("hollow_knight_memory.rs", &memory_rs);
+    let sources = Sources {
+        splits: fn_bodies(&splits_file),
+        game_manager_finder: impl_fn_bodies(&memory_file, "GameManagerFinder"),
+        player_data_store: impl_fn_bodies(&memory_file, "PlayerDataStore"),
+        player_data_paths: player_data_paths(&memory_file),
+    };
+    let mut entries = split_entries(&splits_rs, &splits_file);
+    for (function, kind) in EVALUATIONS {
+        let body = sources.splits.get(function).unwrap_or_else(|| panic!("missing fn {} in splits.rs", function));
+        for arm in match_arms(body) {
+            let mut visited = BTreeSet::new();
+            let fields = sources.fields(Receiver::Splits, arm.body, &mut visited);
+            for key in arm.keys {
+                let Some(entry) = entries.iter_mut().find(|e| e.key == key) else {
+                    eprintln!("WARNING: {} matches unknown split {}", function, key);
+                    continue;
+                };
+                // an arm that always returns false only keeps the PlayerDataStore up to date for another function
+                if !arm.always_false {
+                    entry.evaluation.push(kind);
+                }
+                entry.player_data.extend(fields.iter().cloned());
+            }
+        }
+    }
+    for entry in entries.iter().filter(|e| e.evaluation.is_empty()) {
+        eprintln!("WARNING: split {} is not evaluated by any split function", entry.key);
+    }
+    let json = serde_json::to_string_pretty(&entries).expect("serialize split catalogue");
+    let path = format!("{}/split_catalogue.json", SRC);
+    std::fs::write(&path, json + "\n").unwrap_or_else(|e| panic!("write {}: {}", path, e));
+    println!("wrote {} splits to {}", entries.len(), path);
+}
+
+fn read_src(name: &str) -> String {
+    let path = format!("{}/{}", SRC, name);
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("read {}: {}", path, e))
+}
+
+fn parse_src(name: &str, src: &str) -> File {
+    syn::parse_file(src).unwrap_or_else(|e| panic!("parse {}: {}", name, e))
+}
+
+/// The variants of `pub enum Split`, with their doc comments and `// region:` groupings
+fn split_entries(splits_rs: &str, splits_file: &File) -> Vec<SplitEntry> {
+    let split_enum = splits_file.items.iter().find_map(|item| match item {
+        Item::Enum(e) if e.ident == "Split" => Some(e),
+        _ => None,
+    }).expect("missing enum Split in splits.rs");
+    // comments aren't in the syntax tree, so the regions come from the lines before each variant
+    let lines: Vec<&str> = splits_rs.lines().collect();
+    let start = split_enum.ident.span().start().line;
+    let mut entries = vec![];
+    let mut region = String::new();
+    let mut line = start;
+    for v in split_enum.variants.iter() {
+        let v_line = v.ident.span().start().line;
+        for l in &lines[line..v_line] {
+            if let Some(r) = l.trim().strip_prefix("// region:") {
+                region = r.trim().to_string();
+            }
+        }
+        line = v_line;
+        let key = v.ident.to_string();
+        let (description, tooltip) = description_tooltip(&docs(&v.attrs));
+        let description = if description.is_empty() { key.clone() } else { description };
+        entries.push(SplitEntry { key, description, tooltip, region: region.clone(), evaluation: vec![], player_data: BTreeSet::new() });
+    }
+    entries
+}
+
+/// The lines of the doc comments in the attributes
+fn docs(attrs: &[Attribute]) -> Vec<String> {
+    attrs.iter().filter_map(|a| match &a.meta {
+        Meta::NameValue(nv) if nv.path.is_ident("doc") => match &nv.value {
+            Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Some(s.value().trim().to_string()),
+            _ => None,
+        },
+        _ => None,
+    }).collect()
+}
+
+/// The description and tooltip from doc comment lines, the same way as the RadioButtonOptions derive
+fn description_tooltip(docs: &[String]) -> (String, String) {
+    let paragraphs: Vec<String> = docs.split(|l| l.is_empty()).map(|ls| ls.join(" ")).collect();
+    match paragraphs.split_first() {
+        None => (String::new(), String::new()),
+        Some((description, rest)) => (description.clone(), rest.join("\n")),
+    }
+}
+
+/// The bodies of the top-level functions, by name
+fn fn_bodies(file: &File) -> BTreeMap<String, &Block> {
+    file.items.iter().filter_map(|item| match item {
+        Item::Fn(f) => Some((f.sig.ident.to_string(), f.block.as_ref())),
+        _ => None,
+    }).collect()
+}
+
+/// The bodies of the functions in `impl name { ... }`, by name
+fn impl_fn_bodies<'a>(file: &'a File, name: &str) -> BTreeMap<String, &'a Block> {
+    impl_items(file, name).into_iter().filter_map(|item| match item {
+        ImplItem::Fn(f) => Some((f.sig.ident.to_string(), &f.block)),
+        _ => None,
+    }).collect()
+}
+
+/// The items of the inherent impls of the type `name`
+fn impl_items<'a>(file: &'a File, name: &str) -> Vec<&'a ImplItem> {
+    file.items.iter().filter_map(|item| match item {
+        Item::Impl(i) if i.trait_.is_none() && matches!(&*i.self_ty, Type::Path(t) if t.path.is_ident(name)) => Some(&i.items),
+        _ => None,
+    }).flatten().collect()
+}
+
+/// An arm of the `match s` in a split function
+struct MatchArm<'a> {
+    keys: Vec<String>,
+    body: &'a Expr,
+    /// Whether the arm's value is always `false`, so that it never splits
+    always_false: bool,
+}
+
+/// The arms of the `match` on splits in a split function, with the split keys of each
+fn match_arms(body: &Block) -> Vec<MatchArm<'_>> {
+    let split_match = body.stmts.iter().find_map(|stmt| match stmt {
+        Stmt::Expr(Expr::Match(m), _) => Some(m),
+        _ => None,
+    }).expect("split function without a match");
+    split_match.arms.iter().map(|arm| {
+        let mut keys = vec![];
+        pattern_keys(&arm.pat, &mut keys);
+        MatchArm { keys, body: &arm.body, always_false: is_always_false(&arm.body) }
+    }).collect()
+}
+
+/// The keys of the `Split::` variants in a pattern
+fn pattern_keys(pat: &Pat, keys: &mut Vec<String>) {
+    let path = match pat {
+        Pat::Or(or) => {
+            for case in or.cases.iter() {
+                pattern_keys(case, keys);
+            }
+            return;
+        }
+        Pat::Path(p) => &p.path,
+        Pat::TupleStruct(p) => &p.path,
+        Pat::Struct(p) => &p.path,
+        _ => return,
+    };
+    if let Some(key) = split_key(path) {
+        keys.push(key);
+    }
+}
+
+fn split_key(path: &Path) -> Option<String> {
+    match path.segments.iter().map(|s| s.ident.to_string()).collect::<Vec<String>>().as_slice() {
+        [split, key] if split == "Split" => Some(key.clone()),
+        _ => None,
+    }
+}
+
+/// Whether the expression is `false`, or a block that ends with `false`
+fn is_always_false(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Bool(b), .. }) => !b.value,
+        Expr::Block(b) => matches!(b.block.stmts.last(), Some(Stmt::Expr(e, None)) if is_always_false(e)),
+        _ => false,
+    }
+}
+
+/// The paths after `playerData` of the fields in `PlayerDataPointers::new`
+fn player_data_paths(file: &File) -> BTreeMap<String, String> {
+    let Some(ImplItem::Fn(new)) = impl_items(file, "PlayerDataPointers").into_iter().find(|item| matches!(item, ImplItem::Fn(f) if f.sig.ident == "new")) else {
+        panic!("missing fn PlayerDataPointers::new in hollow_knight_memory.rs");
+    };
+    let Some(Stmt::Expr(Expr::Struct(pointers), None)) = new.block.stmts.last() else {
+        panic!("PlayerDataPointers::new should end with a PlayerDataPointers struct expression");
+    };
+    pointers.fields.iter().filter_map(|f| {
+        let Member::Named(field) = &f.member else { return None; };
+        let Expr::Call(call) = &f.expr else { return None; };
+        let Some(Expr::Reference(r)) = call.args.last() else { return None; };
+        let Expr::Array(path) = &*r.expr else { return None; };
+        let path: Vec<String> = path.elems.iter().filter_map(|e| match e {
+            Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Some(s.value()),
+            _ => None,
+        }).collect();
+        let after = path.iter().position(|s| s == "playerData")? + 1;
+        Some((field.to_string(), path[after..].join(".")))
+    }).collect()
+}
+
+impl Sources<'_> {
+    /// The PlayerData fields read by the code, following calls to functions of the receiver and method calls on `g` and `pds`
+    fn fields(&self, receiver: Receiver, code: &Expr, visited: &mut BTreeSet<(Receiver, String)>) -> BTreeSet<String> {
+        let mut calls = Calls::default();
+        calls.visit_expr(code);
+        self.calls_fields(receiver, calls.0, visited)
+    }
+
+    fn block_fields(&self, receiver: Receiver, code: &Block, visited: &mut BTreeSet<(Receiver, String)>) -> BTreeSet<String> {
+        let mut calls = Calls::default();
+        calls.visit_block(code);
+        self.calls_fields(receiver, calls.0, visited)
+    }
+
+    fn calls_fields(&self, receiver: Receiver, calls: Vec<(String, String)>, visited: &mut BTreeSet<(Receiver, String)>) -> BTreeSet<String> {
+        let mut fields = BTreeSet::new();
+        for (object, name) in calls {
+            if object == "player_data_pointers" {
+                if let Some(path) = self.player_data_paths.get(&name) {
+                    fields.insert(path.clone());
+                }
+                continue;
+            }
+            let callee = match (receiver, object.as_str()) {
+                (Receiver::Splits, "") => Receiver::Splits,
+                (_, "g" | "game_manager_finder") => Receiver::GameManagerFinder,
+                (Receiver::Splits, "pds") => Receiver::PlayerDataStore,
+                (Receiver::GameManagerFinder | Receiver::PlayerDataStore, "self") => receiver,
+                _ => continue,
+            };
+            let bodies = match callee {
+                Receiver::Splits => &self.splits,
+                Receiver::GameManagerFinder => &self.game_manager_finder,
+                Receiver::PlayerDataStore => &self.player_data_store,
+            };
+            let Some(body) = bodies.get(&name) else { continue; };
+            if visited.insert((callee, name)) {
+                fields.extend(self.block_fields(callee, body, visited));
+            }
+        }
+        fields
+    }
+}
+
+/// The `object.name` method calls and field accesses, where `object` is a variable or a field,
+/// and the bare `name` function calls with an empty `object`
+#[derive(Default)]
+struct Calls(Vec<(String, String)>);
+
+impl<'ast> Visit<'ast> for Calls {
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if let Some(object) = object_name(&call.receiver) {
+            self.0.push((object, call.method.to_string()));
+        }
+        syn::visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_field(&mut self, field: &'ast syn::ExprField) {
+        if let (Some(object), Member::Named(name)) = (object_name(&field.base), &field.member) {
+            self.0.push((object, name.to_string()));
+        }
+        syn::visit::visit_expr_field(self, field);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        if let Expr::Path(p) = &*call.func {
+            if let Some(name) = p.path.get_ident() {
+                self.0.push((String::new(), name.to_string()));
+            }
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        // macro arguments aren't parsed, so look for `object.name` in their tokens
+        tokens_calls(mac.tokens.clone(), &mut self.0);
+    }
+}
+
+/// The name of a variable, or of the last field of a field access
+fn object_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(p) => Some(p.path.get_ident()?.to_string()),
+        Expr::Field(f) => match &f.member {
+            Member::Named(name) => Some(name.to_string()),
+            Member::Unnamed(_) => None,
+        },
+        Expr::Reference(r) => object_name(&r.expr),
+        _ => None,
+    }
+}
+
+fn tokens_calls(tokens: TokenStream, calls: &mut Vec<(String, String)>) {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    for (i, token) in tokens.iter().enumerate() {
+        match (token, tokens.get(i + 1), tokens.get(i + 2)) {
+            (TokenTree::Group(g), _, _) => tokens_calls(g.stream(), calls),
+            (TokenTree::Ident(object), Some(TokenTree::Punct(dot)), Some(TokenTree::Ident(name))) if dot.as_char() == '.' => {
+                calls.push((object.to_string(), name.to_string()));
+            }
+            _ => {}
+        }
+    }
+}
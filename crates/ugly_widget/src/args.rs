@@ -9,6 +9,13 @@ pub trait SetHeadingLevel {
     fn set_heading_level(&mut self, heading_level: u32);
 }
 
+/// The Args of structs that derive Widget
+#[derive(Clone, Default, SetHeadingLevel)]
+#[non_exhaustive]
+pub struct StructArgs {
+    pub heading_level: u32,
+}
+
 impl SetHeadingLevel for TitleArgs {
     fn set_heading_level(&mut self, heading_level: u32) {
         self.heading_level = heading_level;
@@ -0,0 +1,216 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use asr::settings::gui::{add_bool, add_title, set_tooltip, Widget};
+
+use crate::store::{value_equal_now, StoreWidget};
+
+use super::args::SetHeadingLevel;
+
+// --------------------------------------------------------
+
+/// The most changes that can be undone
+const HISTORY_LIMIT: usize = 64;
+
+#[derive(Clone, Default, SetHeadingLevel)]
+#[non_exhaustive]
+pub struct HistoryArgs {
+    pub heading_level: u32,
+}
+
+/// The settings that one change stored, with only their values before and after
+struct Change {
+    keys: Vec<String>,
+    before: asr::settings::Map,
+    after: asr::settings::Map,
+}
+
+/// Undo and Redo buttons for the changes stored by a StoreGui with a History field.
+///
+/// Each change only remembers the settings of the GUI that changed since the last time the settings were loaded,
+/// so undoing it leaves alone the settings stored by anything else, such as split times, before or after it.
+#[derive(Default)]
+pub struct History {
+    last: Option<asr::settings::Map>,
+    undo: VecDeque<Change>,
+    redo: Vec<Change>,
+    key: String,
+}
+
+impl Widget for History {
+    type Args = HistoryArgs;
+
+    fn register(key: &str, description: &str, args: Self::Args) -> Self {
+        add_title(key, description, args.heading_level);
+        add_bool(&format!("{}_undo", key), "Undo last change", false);
+        add_bool(&format!("{}_redo", key), "Redo", false);
+        let history = History { key: key.to_string(), ..Default::default() };
+        history.show_counts();
+        history
+    }
+
+    fn update_from(&mut self, _settings_map: &asr::settings::Map, key: &str, _args: Self::Args) {
+        self.key = key.to_string();
+    }
+}
+
+impl StoreWidget for History {
+    /// Releases the Undo and Redo buttons
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let a = false.insert_into(settings_map, &format!("{}_undo", key));
+        let b = false.insert_into(settings_map, &format!("{}_redo", key));
+        a || b
+    }
+}
+
+impl History {
+    /// Applies the Undo or Redo button if it's pressed in the loaded settings,
+    /// returning the settings with the change undone or redone, if there was one
+    pub fn undo_redo(&mut self, settings_map: &asr::settings::Map) -> Option<asr::settings::Map> {
+        let pressed = |suffix: &str| {
+            settings_map.get(&format!("{}_{}", self.key, suffix)).and_then(|v| v.get_bool()).unwrap_or(false)
+        };
+        let restored = if pressed("undo") {
+            self.undo.pop_back().map(|change| {
+                let restored = change.restore(&change.before, settings_map);
+                self.redo.push(change);
+                restored
+            })
+        } else if pressed("redo") {
+            self.redo.pop().map(|change| {
+                let restored = change.restore(&change.after, settings_map);
+                self.undo.push_back(change);
+                restored
+            })
+        } else {
+            None
+        };
+        self.show_counts();
+        restored
+    }
+
+    /// Remembers the settings after they're loaded or stored,
+    /// and the change since the last time unless it was an undo or redo,
+    /// looking only at the settings that `owns_key` says belong to the settings GUI
+    pub fn record(&mut self, settings_map: asr::settings::Map, undone: bool, owns_key: fn(&str) -> bool) {
+        if let (Some(before), false) = (self.last.take(), undone) {
+            if let Some(change) = Change::between(&before, &settings_map, owns_key) {
+                if HISTORY_LIMIT <= self.undo.len() {
+                    self.undo.pop_front();
+                }
+                self.undo.push_back(change);
+                self.redo.clear();
+                self.show_counts();
+            }
+        }
+        self.last = Some(settings_map);
+    }
+
+    fn show_counts(&self) {
+        set_tooltip(&format!("{}_undo", self.key), &format!("{} changes to undo", self.undo.len()));
+        set_tooltip(&format!("{}_redo", self.key), &format!("{} changes to redo", self.redo.len()));
+    }
+}
+
+impl Change {
+    /// The settings for which `owns_key` is true that differ between `before` and `after`,
+    /// including the ones that only one of them has, or None if there are none
+    fn between(before: &asr::settings::Map, after: &asr::settings::Map, owns_key: fn(&str) -> bool) -> Option<Change> {
+        let mut change = Change { keys: vec![], before: asr::settings::Map::new(), after: asr::settings::Map::new() };
+        for (k, after_v) in after.iter().filter(|(k, _)| owns_key(k)) {
+            let before_v = before.get(&k);
+            let same = before_v.as_ref().is_some_and(|before_v| value_equal_now(before_v, &after_v));
+            if same {
+                continue;
+            }
+            if let Some(before_v) = before_v {
+                change.before.insert(&k, &before_v);
+            }
+            change.after.insert(&k, &after_v);
+            change.keys.push(k);
+        }
+        for (k, before_v) in before.iter().filter(|(k, _)| owns_key(k)) {
+            if after.get(&k).is_none() {
+                change.before.insert(&k, &before_v);
+                change.keys.push(k);
+            }
+        }
+        if change.keys.is_empty() { None } else { Some(change) }
+    }
+
+    /// The settings with the changed ones set to their values in `values`, the settings before or after the change,
+    /// and without the changed ones that `values` doesn't have.
+    /// Settings maps can't remove keys, so this copies the other settings into a new map.
+    fn restore(&self, values: &asr::settings::Map, settings_map: &asr::settings::Map) -> asr::settings::Map {
+        let restored = asr::settings::Map::new();
+        for (k, v) in settings_map.iter() {
+            match (self.keys.contains(&k), values.get(&k)) {
+                (false, _) => restored.insert(&k, &v),
+                (true, Some(v)) => restored.insert(&k, &v),
+                (true, None) => (),
+            }
+        }
+        for k in self.keys.iter() {
+            if let (None, Some(v)) = (restored.get(k), values.get(k)) {
+                restored.insert(k, &v);
+            }
+        }
+        restored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owns_splits(key: &str) -> bool {
+        key.starts_with("splits")
+    }
+
+    /// Settings with a list of splits, and the item settings of the splits
+    fn splits_map(splits: &[&str]) -> asr::settings::Map {
+        let settings_map = asr::settings::Map::new();
+        let list = asr::settings::List::new();
+        for (i, s) in splits.iter().enumerate() {
+            list.push(*s);
+            settings_map.insert(&format!("splits_{}_item", i), *s);
+        }
+        settings_map.insert("splits", &list);
+        settings_map.insert("split_times_hash", 1_i64);
+        settings_map
+    }
+
+    fn splits_in(settings_map: &asr::settings::Map) -> Vec<String> {
+        let list = settings_map.get("splits").and_then(|v| v.get_list());
+        list.map(|l| l.iter().filter_map(|v| v.get_string()).collect()).unwrap_or_default()
+    }
+
+    fn pressed(settings_map: &asr::settings::Map, suffix: &str) -> asr::settings::Map {
+        let pressed = settings_map.clone();
+        pressed.insert(&format!("history_{}", suffix), true);
+        pressed
+    }
+
+    #[test]
+    fn undo_redo_added_list_item() {
+        let mut history = History { key: "history".to_string(), ..Default::default() };
+        history.record(splits_map(&["StartNewGame"]), false, owns_splits);
+        let added = splits_map(&["StartNewGame", "EndingSplit"]);
+        added.insert("split_times_hash", 2_i64);
+        history.record(added.clone(), false, owns_splits);
+
+        let undone = history.undo_redo(&pressed(&added, "undo")).expect("the change should be undone");
+        assert_eq!(splits_in(&undone), ["StartNewGame"]);
+        assert!(undone.get("splits_1_item").is_none());
+        // the settings that the GUI doesn't own stay the same
+        assert_eq!(undone.get("split_times_hash").and_then(|v| v.get_i64()), Some(2));
+        history.insert_into(&undone, "history");
+        history.record(undone.clone(), true, owns_splits);
+
+        let redone = history.undo_redo(&pressed(&undone, "redo")).expect("the change should be redone");
+        assert_eq!(splits_in(&redone), ["StartNewGame", "EndingSplit"]);
+        assert_eq!(redone.get("splits_1_item").and_then(|v| v.get_string()).as_deref(), Some("EndingSplit"));
+        history.insert_into(&redone, "history");
+        assert!(history.undo_redo(&redone).is_none());
+    }
+}
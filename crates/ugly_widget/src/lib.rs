@@ -3,6 +3,10 @@ extern crate alloc;
 extern crate self as ugly_widget;
 
 pub mod args;
+pub mod history;
 pub mod radio_button;
 pub mod store;
 pub mod ugly_list;
+pub mod ugly_number;
+pub mod ugly_picker;
+pub mod ugly_text;
@@ -16,6 +16,12 @@ pub struct RadioButtonOption<'a, T> {
     pub key: &'a str,
     pub description: &'a str,
     pub tooltip: Option<&'a str>,
+    /// From a `#[category = "..."]` attribute, for grouping options
+    pub category: Option<&'a str>,
+    /// From a `#[deprecated_option]` attribute, for options kept for old settings that shouldn't be chosen anymore
+    pub deprecated: bool,
+    /// From a `#[hidden]` attribute, for options that are valid values but aren't shown
+    pub hidden: bool,
 }
 
 #[derive(Clone, Default, SetHeadingLevel)]
@@ -38,10 +44,10 @@ impl<T: RadioButtonOptions> Widget for RadioButton<T> {
     fn register(key: &str, description: &str, args: Self::Args) -> Self {
         add_title(key, description, args.heading_level);
         let default = args.default_value::<T>();
-        let bool_map: BTreeMap<T, bool> = T::radio_button_options().into_iter().map(|o| {
+        let bool_map: BTreeMap<T, bool> = T::radio_button_options().into_iter().filter(|o| !o.hidden).map(|o| {
             let bool_key = o.bool_key(key);
             let b = add_bool(&bool_key, &o.description, o.value == default);
-            if let Some(t) = o.tooltip {
+            if let Some(t) = o.shown_tooltip() {
                 set_tooltip(&bool_key, &t);
             }
             (o.value, b)
@@ -53,7 +59,7 @@ impl<T: RadioButtonOptions> Widget for RadioButton<T> {
         let default = args.default_value::<T>();
         let old = settings_map.get(key).and_then(|v| v.get_string()).and_then(|s| options_value::<T>(&s)).unwrap_or(default.clone());
         let options = T::radio_button_options();
-        let new_bools: Vec<(&T, bool)> = options.iter().filter_map(|o| {
+        let new_bools: Vec<(&T, bool)> = options.iter().filter(|o| !o.hidden).filter_map(|o| {
             let bool_key = o.bool_key(key);
             let old_b = old == o.value;
             let map_b = settings_map.get(&bool_key).and_then(|v| v.get_bool()).unwrap_or(old_b);
@@ -80,7 +86,7 @@ impl<T: RadioButtonOptions> StoreWidget for RadioButton<T> {
         }
         settings_map.insert(key, new_s);
         set_tooltip(key, new_s);
-        for o in T::radio_button_options() {
+        for o in T::radio_button_options().into_iter().filter(|o| !o.hidden) {
             let bool_key = o.bool_key(key);
             let new_b = &self.0 == &o.value;
             new_b.insert_into(settings_map, &bool_key);
@@ -95,6 +101,15 @@ impl<T> RadioButtonOption<'_, T> {
     fn bool_key(&self, key: &str) -> String {
         format!("{}_{}", key, self.key)
     }
+
+    /// The tooltip, flagging the option if it's deprecated
+    pub fn shown_tooltip(&self) -> Option<String> {
+        match (self.deprecated, self.tooltip) {
+            (false, t) => t.map(str::to_string),
+            (true, None) => Some("Deprecated".to_string()),
+            (true, Some(t)) => Some(format!("Deprecated: {}", t)),
+        }
+    }
 }
 
 impl RadioButtonArgs<'_> {
@@ -103,9 +118,11 @@ impl RadioButtonArgs<'_> {
     }
 }
 
+/// The key of the option for the variant of `v`, ignoring any data the variant holds
 pub fn options_str<T: RadioButtonOptions>(v: &T) -> &'static str {
+    let d = core::mem::discriminant(v);
     T::radio_button_options().into_iter().find_map(|o| {
-        if &o.value == v {
+        if core::mem::discriminant(&o.value) == d {
             Some(o.key)
         } else {
             None
@@ -113,7 +130,19 @@ pub fn options_str<T: RadioButtonOptions>(v: &T) -> &'static str {
     }).unwrap_or_default()
 }
 
-fn options_value<T: RadioButtonOptions>(s: &str) -> Option<T> {
+/// The description of the option for the variant of `v`, ignoring any data the variant holds
+pub fn options_description<T: RadioButtonOptions>(v: &T) -> &'static str {
+    let d = core::mem::discriminant(v);
+    T::radio_button_options().into_iter().find_map(|o| {
+        if core::mem::discriminant(&o.value) == d {
+            Some(o.description)
+        } else {
+            None
+        }
+    }).unwrap_or_default()
+}
+
+pub fn options_value<T: RadioButtonOptions>(s: &str) -> Option<T> {
     T::radio_button_options().into_iter().find_map(|o| {
         if o.key == s {
             Some(o.value)
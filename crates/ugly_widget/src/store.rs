@@ -1,16 +1,43 @@
 
 use asr::settings::gui::{Gui, Widget};
 
+use crate::history::History;
+
+pub use ugly_widget_derive::{StoreGui, StoreWidget, Widget};
+
 pub trait StoreGui: Gui {
     fn insert_into(&self, settings_map: &asr::settings::Map) -> bool;
 
+    /// The History field that undoes and redoes stored changes, if there is one
+    fn history(&mut self) -> Option<&mut History> {
+        None
+    }
+
+    /// Whether the setting at `key` is stored by this StoreGui rather than by something else,
+    /// so that the History only undoes and redoes the settings of the GUI
+    fn owns_key(_key: &str) -> bool {
+        true
+    }
+
     fn load_update_store_if_unchanged(&mut self) -> bool {
-        let settings_map = asr::settings::Map::load();
-        let old = settings_map.clone();
+        let old = asr::settings::Map::load();
+        let restored = self.history().and_then(|h| h.undo_redo(&old));
+        let undone = restored.is_some();
+        let settings_map = restored.unwrap_or_else(|| old.clone());
         self.update_from(&settings_map);
-        if self.insert_into(&settings_map) {
-            settings_map.store_if_unchanged(&old)
+        if self.insert_into(&settings_map) || undone {
+            let stored = settings_map.store_if_unchanged(&old);
+            if stored {
+                if let Some(h) = self.history() {
+                    h.record(settings_map, undone, Self::owns_key);
+                }
+            }
+            stored
         } else {
+            // the settings GUI may have changed settings that didn't need anything else stored with them
+            if let Some(h) = self.history() {
+                h.record(old, false, Self::owns_key);
+            }
             true
         }
     }
@@ -37,3 +64,82 @@ impl StoreWidget for bool {
         true
     }
 }
+
+/// Whether `key` is the key of one of the fields, or the key of a setting under one, like `{field}_0_item`
+pub fn key_in_fields(key: &str, fields: &[&str]) -> bool {
+    fields.iter().any(|f| key.strip_prefix(f).is_some_and(|rest| rest.is_empty() || rest.starts_with('_')))
+}
+
+/// Copies the fields of a map under `key`, such as a struct loaded as an item of a list,
+/// into the `{key}_{field}` settings that don't exist yet
+pub fn expand_fields_map(settings_map: &asr::settings::Map, key: &str, fields: &[&str]) {
+    let Some(map) = settings_map.get(key).and_then(|v| v.get_map()) else { return; };
+    for field in fields {
+        let key_field = format!("{}_{}", key, field);
+        if settings_map.get(&key_field).is_some() {
+            continue;
+        }
+        if let Some(v) = map.get(field) {
+            settings_map.insert(&key_field, &v);
+        }
+    }
+}
+
+/// Collects the `{key}_{field}` settings into a map under `key`,
+/// if any of them `changed` or there isn't a map there yet
+pub fn insert_fields_map(settings_map: &asr::settings::Map, key: &str, fields: &[&str], changed: bool) -> bool {
+    if !changed && settings_map.get(key).is_some_and(|v| v.get_map().is_some()) {
+        return false;
+    }
+    let map = asr::settings::Map::new();
+    for field in fields {
+        if let Some(v) = settings_map.get(&format!("{}_{}", key, field)) {
+            map.insert(field, &v);
+        }
+    }
+    settings_map.insert(key, &map);
+    true
+}
+
+pub fn value_equal_now(a: &asr::settings::Value, b: &asr::settings::Value) -> bool {
+    let t = a.get_type();
+    if t != b.get_type() { return false; }
+    match t {
+        asr::settings::ValueType::Bool => a.get_bool() == b.get_bool(),
+        asr::settings::ValueType::I64 => a.get_i64() == b.get_i64(),
+        asr::settings::ValueType::F64 => a.get_f64() == b.get_f64(),
+        asr::settings::ValueType::String => a.get_string() == b.get_string(),
+        asr::settings::ValueType::List => {
+            let Some(al) = a.get_list() else { return false; };
+            let Some(bl) = b.get_list() else { return false; };
+            list_equal_now(&al, &bl)
+        },
+        asr::settings::ValueType::Map => {
+            let Some(am) = a.get_map() else { return false; };
+            let Some(bm) = b.get_map() else { return false; };
+            map_equal_now(&am, &bm)
+        },
+        _ => false,
+    }
+}
+
+pub fn list_equal_now(a: &asr::settings::List, b: &asr::settings::List) -> bool {
+    let n = a.len();
+    if n != b.len() { return false; }
+    for i in 0..n {
+        let Some(ai) = a.get(i) else { return false; };
+        let Some(bi) = b.get(i) else { return false; };
+        if !value_equal_now(&ai, &bi) { return false; }
+    }
+    true
+}
+
+pub fn map_equal_now(a: &asr::settings::Map, b: &asr::settings::Map) -> bool {
+    let n = a.len();
+    if n != b.len() { return false; }
+    for (k, av) in a.iter() {
+        let Some(bv) = b.get(&k) else { return false; };
+        if !value_equal_now(&av, &bv) { return false; }
+    }
+    true
+}
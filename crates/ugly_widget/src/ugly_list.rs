@@ -3,42 +3,40 @@ use alloc::vec::Vec;
 
 use asr::settings::gui::{add_bool, add_title, set_tooltip, Gui, Widget};
 
-use crate::store::StoreWidget;
+use crate::store::{value_equal_now, StoreWidget};
 
 use super::args::SetHeadingLevel;
-use super::radio_button::{options_str, RadioButtonOptions};
+use super::radio_button::RadioButtonOptions;
+use super::ugly_number::{UglyNumber, UglyNumberArgs};
 
 // --------------------------------------------------------
 
 // #[derive(Gui)]
-#[derive(Clone, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+#[derive(Clone, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions, StoreWidget)]
 pub enum ListItemAction {
     /// None
     #[default]
     None,
     /// Remove
     Remove,
+    /// Duplicate
+    Duplicate,
     /// Move before
     MoveBefore,
     /// Move after
     MoveAfter,
-    /// Insert before
+    /// Move to top
+    MoveToTop,
+    /// Move to bottom
+    MoveToBottom,
+    /// Move to index N
+    MoveToIndex,
+    /// Insert N before
     InsertBefore,
-    /// Insert after
+    /// Insert N after
     InsertAfter,
 }
 
-impl StoreWidget for ListItemAction {
-    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
-        let new_s = options_str(self);
-        if settings_map.get(key).is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s)) {
-            return false;
-        }
-        settings_map.insert(key, new_s);
-        true
-    }
-}
-
 /*
 impl RadioButtonOptions for ListItemAction {
     fn radio_button_options() -> Vec<RadioButtonOption<'static, Self>> {
@@ -56,6 +54,25 @@ impl RadioButtonOptions for ListItemAction {
 
 // --------------------------------------------------------
 
+/// The buttons for the whole list, as key suffixes and descriptions
+const LIST_ACTIONS: [(&str, &str); 3] = [
+    ("insert_0", "Insert at 0"),
+    ("clear", "Clear"),
+    ("reverse", "Reverse"),
+];
+
+/// Sets the value of an item from another item, when items move to other indices or the whole list is set.
+///
+/// Item widgets that keep state about what they registered at their own key override it to only set the value,
+/// since that state still belongs to the key of the index, not to the item that moved there.
+pub trait ListItemValue: Clone {
+    fn set_value_from(&mut self, other: &Self) {
+        *self = other.clone();
+    }
+}
+
+impl ListItemValue for bool {}
+
 #[derive(Clone, Default, SetHeadingLevel)]
 #[non_exhaustive]
 pub struct UglyListArgs {
@@ -109,6 +126,9 @@ impl<T: StoreWidget> StoreWidget for UglyListItem<T> where T::Args: SetHeadingLe
 pub struct UglyList<T> {
     len: usize,
     ulis: Vec<UglyListItem<T>>,
+    /// The index for Move to index N, or the number of items for Insert N,
+    /// one for the whole list rather than one for each item
+    n: UglyNumber,
 }
 
 impl<T> UglyList<T> {
@@ -117,13 +137,37 @@ impl<T> UglyList<T> {
     }
 }
 
-impl<T: Clone + Widget> Widget for UglyList<T> where T::Args: SetHeadingLevel {
+impl<T: ListItemValue + Widget> UglyList<T> where T::Args: SetHeadingLevel {
+    /// Replaces all the items, registering any that didn't exist before,
+    /// to be stored with insert_into without an update_from in between
+    pub fn set_list(&mut self, key: &str, args: UglyListArgs, items: Vec<T>) {
+        for i in self.ulis.len()..items.len() {
+            let key_i = format!("{}_{}", key, i);
+            self.ulis.push(UglyListItem::register(&key_i, &format!("Item {}", i), args.clone()));
+        }
+        self.len = items.len();
+        for (uli, item) in self.ulis.iter_mut().zip(items) {
+            uli.item.set_value_from(&item);
+            uli.action = ListItemAction::None;
+        }
+        self.n = UglyNumber(0);
+    }
+}
+
+impl<T: ListItemValue + Widget> Widget for UglyList<T> where T::Args: SetHeadingLevel {
     type Args = UglyListArgs;
 
     fn register(key: &str, description: &str, args: Self::Args) -> Self {
         add_title(key, description, args.heading_level);
-        add_bool(&format!("{}_insert_0", key), "Insert at 0", false);
-        UglyList { len: 0, ulis: vec![] }
+        for (suffix, description) in LIST_ACTIONS {
+            add_bool(&format!("{}_{}", key, suffix), description, false);
+        }
+        let key_n = format!("{}_n", key);
+        let mut n_args = UglyNumberArgs::default();
+        n_args.set_heading_level(args.heading_level + 1);
+        let n = UglyNumber::register(&key_n, "N", n_args);
+        set_tooltip(&key_n, "The index for an item's Move to index N, or the number of items for its Insert N, where 0 inserts 1");
+        UglyList { len: 0, ulis: vec![], n }
     }
 
     fn update_from(&mut self, settings_map: &asr::settings::Map, key: &str, args: Self::Args) {
@@ -140,7 +184,13 @@ impl<T: Clone + Widget> Widget for UglyList<T> where T::Args: SetHeadingLevel {
         // --------------------------
         // map_len <= self.ulis.len()
         // --------------------------
-        let insert_0 = settings_map.get(&format!("{}_insert_0", key)).and_then(|v| v.get_bool()).unwrap_or(false);
+        let [insert_0, clear, reverse] = LIST_ACTIONS.map(|(suffix, _)| {
+            settings_map.get(&format!("{}_{}", key, suffix)).and_then(|v| v.get_bool()).unwrap_or(false)
+        });
+        let key_n = format!("{}_n", key);
+        let mut n_args = UglyNumberArgs::default();
+        n_args.set_heading_level(args.heading_level + 1);
+        self.n.update_from(settings_map, &key_n, n_args);
         for i in 0..map_len {
             let key_i = format!("{}_{}", key, i);
             let key_i_item = format!("{}_item", key_i);
@@ -158,15 +208,27 @@ impl<T: Clone + Widget> Widget for UglyList<T> where T::Args: SetHeadingLevel {
         }
         for old_i in 0 .. map_len {
             let new_i = index_of(&index_new_to_old, &(old_i as i64)).unwrap_or_default();
+            let n = self.n.0;
+            let count = n.max(1) as usize;
             match self.ulis[old_i].action {
                 ListItemAction::None => (),
                 ListItemAction::Remove => { index_new_to_old.remove(new_i); () },
-                ListItemAction::InsertBefore => index_new_to_old.insert(new_i, -1),
-                ListItemAction::InsertAfter => index_new_to_old.insert(new_i + 1, -1),
+                ListItemAction::Duplicate => index_new_to_old.insert(new_i + 1, old_i as i64),
+                ListItemAction::InsertBefore => { index_new_to_old.splice(new_i..new_i, vec![-1; count]); },
+                ListItemAction::InsertAfter => { index_new_to_old.splice(new_i + 1..new_i + 1, vec![-1; count]); },
                 ListItemAction::MoveBefore => if 1 <= new_i { index_new_to_old.swap(new_i, new_i - 1) },
                 ListItemAction::MoveAfter => if new_i + 1 < index_new_to_old.len() { index_new_to_old.swap(new_i, new_i + 1) },
+                ListItemAction::MoveToTop => move_to(&mut index_new_to_old, new_i, 0),
+                ListItemAction::MoveToBottom => move_to(&mut index_new_to_old, new_i, usize::MAX),
+                ListItemAction::MoveToIndex => move_to(&mut index_new_to_old, new_i, n.max(0) as usize),
             }
         }
+        if reverse {
+            index_new_to_old.reverse();
+        }
+        if clear {
+            index_new_to_old.clear();
+        }
         let new_len = index_new_to_old.len();
         for i in self.ulis.len()..new_len {
             let key_i = format!("{}_{}", key, i);
@@ -178,20 +240,32 @@ impl<T: Clone + Widget> Widget for UglyList<T> where T::Args: SetHeadingLevel {
         let old_items: Vec<T> = self.ulis.iter().map(|uli| uli.item.clone()).collect();
         for (new_i, old_i) in index_new_to_old.into_iter().enumerate() {
             if 0 <= old_i && new_i as i64 != old_i {
-                self.ulis[new_i].item = old_items[old_i as usize].clone();
+                self.ulis[new_i].item.set_value_from(&old_items[old_i as usize]);
+            }
+        }
+        let mut acted = false;
+        for uli in self.ulis.iter_mut() {
+            if uli.action != ListItemAction::None {
+                uli.action = ListItemAction::None;
+                acted = true;
             }
-            self.ulis[new_i].action = ListItemAction::None;
+        }
+        if acted {
+            self.n = UglyNumber(0);
         }
         self.len = new_len;
     }
 }
 
-impl<T: Clone + StoreWidget> StoreWidget for UglyList<T> where T::Args: SetHeadingLevel {
+impl<T: ListItemValue + StoreWidget> StoreWidget for UglyList<T> where T::Args: SetHeadingLevel {
     fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
         let mut changed = false;
-        let key_insert_0 = &format!("{}_insert_0", key);
-        if !settings_map.get(&key_insert_0).is_some_and(|v| v.get_bool().is_some_and(|b| !b)) {
-            settings_map.insert(&key_insert_0, false);
+        for (suffix, _) in LIST_ACTIONS {
+            if false.insert_into(settings_map, &format!("{}_{}", key, suffix)) {
+                changed = true;
+            }
+        }
+        if self.n.insert_into(settings_map, &format!("{}_n", key)) {
             changed = true;
         }
         let maybe_old_list = settings_map.get(key).and_then(|old_v| old_v.get_list());
@@ -229,6 +303,12 @@ impl<T: Clone + StoreWidget> StoreWidget for UglyList<T> where T::Args: SetHeadi
 
 // --------------------------------------------------------
 
+/// Moves the element at `from` to `to`, or to the end if `to` is past it
+fn move_to<T>(v: &mut Vec<T>, from: usize, to: usize) {
+    let e = v.remove(from);
+    v.insert(to.min(v.len()), e);
+}
+
 fn index_of<T>(slice: &[T], v: &T) -> Option<usize> where T: PartialEq<T> {
     for (i, e) in slice.into_iter().enumerate() {
         if e == v {
@@ -238,45 +318,63 @@ fn index_of<T>(slice: &[T], v: &T) -> Option<usize> where T: PartialEq<T> {
     None
 }
 
-fn value_equal_now(a: &asr::settings::Value, b: &asr::settings::Value) -> bool {
-    let t = a.get_type();
-    if t != b.get_type() { return false; }
-    match t {
-        asr::settings::ValueType::Bool => a.get_bool() == b.get_bool(),
-        asr::settings::ValueType::I64 => a.get_i64() == b.get_i64(),
-        asr::settings::ValueType::F64 => a.get_f64() == b.get_f64(),
-        asr::settings::ValueType::String => a.get_string() == b.get_string(),
-        asr::settings::ValueType::List => {
-            let Some(al) = a.get_list() else { return false; };
-            let Some(bl) = b.get_list() else { return false; };
-            list_equal_now(&al, &bl)
-        },
-        asr::settings::ValueType::Map => {
-            let Some(am) = a.get_map() else { return false; };
-            let Some(bm) = b.get_map() else { return false; };
-            map_equal_now(&am, &bm)
-        },
-        _ => false,
+#[cfg(test)]
+mod tests {
+    use asr::settings::gui::Widget;
+
+    use crate::store::StoreWidget;
+    use crate::ugly_text::UglyText;
+
+    use super::*;
+
+    /// An item with more than one field, stored as a map in the list
+    #[derive(Clone, ugly_widget_derive::Widget, ugly_widget_derive::StoreWidget)]
+    struct Entry {
+        /// Name
+        name: UglyText,
+        /// Enabled
+        #[default = true]
+        enabled: bool,
     }
-}
 
-fn list_equal_now(a: &asr::settings::List, b: &asr::settings::List) -> bool {
-    let n = a.len();
-    if n != b.len() { return false; }
-    for i in 0..n {
-        let Some(ai) = a.get(i) else { return false; };
-        let Some(bi) = b.get(i) else { return false; };
-        if !value_equal_now(&ai, &bi) { return false; }
+    fn entry(name: &str, enabled: bool) -> Entry {
+        Entry { name: UglyText(name.to_string()), enabled }
     }
-    true
-}
 
-fn map_equal_now(a: &asr::settings::Map, b: &asr::settings::Map) -> bool {
-    let n = a.len();
-    if n != b.len() { return false; }
-    for (k, av) in a.iter() {
-        let Some(bv) = b.get(&k) else { return false; };
-        if !value_equal_now(&av, &bv) { return false; }
+    /// The name and enabled fields of the maps in the list
+    fn entries_in(settings_map: &asr::settings::Map) -> Vec<(String, bool)> {
+        let list = settings_map.get("list").and_then(|v| v.get_list()).expect("the list should be stored");
+        list.iter().map(|v| {
+            let map = v.get_map().expect("the item should be a map");
+            let name = map.get("name").and_then(|v| v.get_string()).unwrap_or_default();
+            let enabled = map.get("enabled").and_then(|v| v.get_bool()).unwrap_or_default();
+            (name, enabled)
+        }).collect()
+    }
+
+    #[test]
+    fn struct_items() {
+        let settings_map = asr::settings::Map::new();
+        let mut list = UglyList::<Entry>::register("list", "List", UglyListArgs::default());
+        list.set_list("list", UglyListArgs::default(), vec![entry("a", true), entry("b", false)]);
+        assert!(list.insert_into(&settings_map, "list"));
+        assert_eq!(entries_in(&settings_map), [("a".to_string(), true), ("b".to_string(), false)]);
+        assert_eq!(settings_map.get("list_1_item_name").and_then(|v| v.get_string()).as_deref(), Some("b"));
+
+        // a list loaded with only the maps fills in the field settings from them
+        let loaded = asr::settings::Map::new();
+        loaded.insert("list", &settings_map.get("list").unwrap());
+        let mut list = UglyList::<Entry>::register("list", "List", UglyListArgs::default());
+        list.update_from(&loaded, "list", UglyListArgs::default());
+        let items: Vec<(String, bool)> = list.get_list().into_iter().map(|e| (e.name.0.clone(), e.enabled)).collect();
+        assert_eq!(items, [("a".to_string(), true), ("b".to_string(), false)]);
+
+        // moving an item moves all of its fields
+        loaded.insert("list_0_action", "MoveAfter");
+        list.update_from(&loaded, "list", UglyListArgs::default());
+        list.insert_into(&loaded, "list");
+        assert_eq!(entries_in(&loaded), [("b".to_string(), false), ("a".to_string(), true)]);
+        assert_eq!(loaded.get("list_0_item_enabled").and_then(|v| v.get_bool()), Some(false));
+        assert_eq!(loaded.get("list_1_item_name").and_then(|v| v.get_string()).as_deref(), Some("a"));
     }
-    true
 }
@@ -0,0 +1,74 @@
+use asr::settings::gui::{add_bool, add_title, set_tooltip, Widget};
+
+use crate::store::StoreWidget;
+use crate::ugly_list::ListItemValue;
+
+use super::args::SetHeadingLevel;
+
+// --------------------------------------------------------
+
+/// The buttons for changing the number, as key suffixes, descriptions, and amounts
+const STEPS: [(&str, &str, i64); 6] = [
+    ("add_100", "+100", 100),
+    ("add_10", "+10", 10),
+    ("add_1", "+1", 1),
+    ("sub_1", "-1", -1),
+    ("sub_10", "-10", -10),
+    ("sub_100", "-100", -100),
+];
+
+#[derive(Clone, Default, SetHeadingLevel)]
+#[non_exhaustive]
+pub struct UglyNumberArgs {
+    pub heading_level: u32,
+    pub default: i64,
+}
+
+/// A number that is changed with buttons, since there is no number input,
+/// and is shown in the tooltip of its title
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct UglyNumber(pub i64);
+
+impl Widget for UglyNumber {
+    type Args = UglyNumberArgs;
+
+    fn register(key: &str, description: &str, args: Self::Args) -> Self {
+        add_title(key, description, args.heading_level);
+        for (suffix, step_description, _) in STEPS {
+            add_bool(&format!("{}_{}", key, suffix), step_description, false);
+        }
+        let mut n = UglyNumber(args.default);
+        n.update_from(&asr::settings::Map::load(), key, args);
+        set_tooltip(key, &n.0.to_string());
+        n
+    }
+
+    fn update_from(&mut self, settings_map: &asr::settings::Map, key: &str, args: Self::Args) {
+        let old = settings_map.get(key).and_then(|v| v.get_i64()).unwrap_or(args.default);
+        let new = STEPS.into_iter().fold(old, |n, (suffix, _, step)| {
+            let pressed = settings_map.get(&format!("{}_{}", key, suffix)).and_then(|v| v.get_bool()).unwrap_or(false);
+            if pressed { n.saturating_add(step) } else { n }
+        });
+        self.0 = new;
+    }
+}
+
+impl ListItemValue for UglyNumber {}
+
+impl StoreWidget for UglyNumber {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let mut changed = false;
+        let same = settings_map.get(key).is_some_and(|old_v| old_v.get_i64().is_some_and(|old_n| old_n == self.0));
+        if !same {
+            settings_map.insert(key, self.0);
+            set_tooltip(key, &self.0.to_string());
+            changed = true;
+        }
+        for (suffix, _, _) in STEPS {
+            if false.insert_into(settings_map, &format!("{}_{}", key, suffix)) {
+                changed = true;
+            }
+        }
+        changed
+    }
+}
@@ -0,0 +1,178 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use asr::settings::gui::{add_choice, add_choice_option, set_tooltip, Widget};
+
+use crate::store::StoreWidget;
+use crate::ugly_text::{UglyText, UglyTextArgs};
+
+use super::args::SetHeadingLevel;
+use super::radio_button::{options_description, options_str, options_value, RadioButtonOption, RadioButtonOptions};
+
+// --------------------------------------------------------
+
+/// The category choice option that adds the options of every category
+const ALL_CATEGORIES: &str = "*";
+
+/// The category of options without a `#[category = "..."]` attribute
+const OTHER_CATEGORY: &str = "Other";
+
+#[derive(Clone, Default, SetHeadingLevel)]
+#[non_exhaustive]
+pub struct UglyPickerArgs {
+    pub heading_level: u32,
+}
+
+/// A choice between many options, grouped into categories.
+///
+/// A Category choice comes first, and the choice of the option itself only has the options
+/// of the categories chosen so far, starting with the category of the current value,
+/// since options can be added to a choice after it's registered, but not removed.
+/// A Filter under the choice, which is set like an UglyText,
+/// limits the options added from then on to the ones containing its text.
+#[derive(Clone)]
+pub struct UglyPicker<T> {
+    pub value: T,
+    /// The keys of the options that have been added to the choice
+    added: BTreeSet<&'static str>,
+    /// The categories that have been searched for options, with the filter at the time
+    searched: BTreeSet<(&'static str, String)>,
+}
+
+impl<T> UglyPicker<T> {
+    /// A picker for a value, that hasn't added any options yet
+    pub fn new(value: T) -> UglyPicker<T> {
+        UglyPicker { value, added: BTreeSet::new(), searched: BTreeSet::new() }
+    }
+}
+
+impl<T: RadioButtonOptions> Widget for UglyPicker<T> {
+    type Args = UglyPickerArgs;
+
+    fn register(key: &str, description: &str, args: Self::Args) -> Self {
+        let settings_map = asr::settings::Map::load();
+        let value = stored_value::<T>(&settings_map, key);
+        let key_category = format!("{}_category", key);
+        add_choice(&key_category, "Category", value_category(&value));
+        add_choice_option(&key_category, ALL_CATEGORIES, "All categories");
+        for c in categories::<T>() {
+            add_choice_option(&key_category, c, c);
+        }
+        set_tooltip(&key_category, "Adds the options in this category to the choice below");
+        add_choice(key, description, options_str(&value));
+        let key_filter = format!("{}_filter", key);
+        let mut filter_args = UglyTextArgs::default();
+        filter_args.set_heading_level(args.heading_level + 1);
+        UglyText::register(&key_filter, "Filter", filter_args);
+        set_tooltip(&key_filter, "Only options containing this text are added from the categories chosen above. Set it by editing the settings outside of the settings GUI.");
+        let mut picker = UglyPicker::new(value);
+        picker.update_from(&settings_map, key, args);
+        picker
+    }
+
+    fn update_from(&mut self, settings_map: &asr::settings::Map, key: &str, _args: Self::Args) {
+        // a stored value that isn't one of the options leaves the value alone
+        if let Some(v) = settings_map.get(key).and_then(|v| v.get_string()).and_then(|s| options_value::<T>(&s)) {
+            self.value = v;
+        }
+        let filter = settings_map.get(&format!("{}_filter", key)).and_then(|v| v.get_string()).unwrap_or_default();
+        let filter = filter.trim().to_lowercase();
+        self.add_category(key, value_category(&self.value), &filter);
+        self.add_value(key);
+        let category = settings_map.get(&format!("{}_category", key)).and_then(|v| v.get_string());
+        if let Some(c) = category {
+            if c == ALL_CATEGORIES {
+                for c in categories::<T>() {
+                    self.add_category(key, c, &filter);
+                }
+            } else if let Some(c) = categories::<T>().into_iter().find(|k| *k == c) {
+                self.add_category(key, c, &filter);
+            }
+        }
+    }
+}
+
+impl<T: RadioButtonOptions> StoreWidget for UglyPicker<T> {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(&self.value);
+        if settings_map.get(key).is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s)) {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        set_tooltip(key, options_description(&self.value));
+        // show the category of the new value, unless the options of every category were added
+        let key_category = format!("{}_category", key);
+        let category = settings_map.get(&key_category).and_then(|v| v.get_string()).unwrap_or_default();
+        if category != ALL_CATEGORIES {
+            settings_map.insert(&key_category, value_category(&self.value));
+        }
+        true
+    }
+}
+
+impl<T: RadioButtonOptions> UglyPicker<T> {
+    /// Adds the option of the current value to the choice, whether or not it matches the filter
+    fn add_value(&mut self, key: &str) {
+        let s = options_str(&self.value);
+        if let Some(o) = T::radio_button_options().into_iter().find(|o| o.key == s) {
+            self.add_option(key, &o);
+        }
+    }
+
+    /// Adds the options of the category that contain the lowercase filter to the choice,
+    /// unless the category has been searched with that filter already
+    fn add_category(&mut self, key: &str, category: &'static str, filter: &str) {
+        if !self.searched.insert((category, filter.to_string())) {
+            return;
+        }
+        for o in T::radio_button_options() {
+            if o.hidden || option_category(&o) != category || !option_matches(&o, filter) {
+                continue;
+            }
+            self.add_option(key, &o);
+        }
+    }
+
+    /// Adds the option to the choice, unless it's been added already
+    fn add_option(&mut self, key: &str, o: &RadioButtonOption<'static, T>) {
+        if !self.added.insert(o.key) {
+            return;
+        }
+        if o.deprecated {
+            add_choice_option(key, o.key, &format!("{} - Deprecated", o.description));
+        } else {
+            add_choice_option(key, o.key, o.description);
+        }
+    }
+}
+
+// --------------------------------------------------------
+
+fn stored_value<T: RadioButtonOptions>(settings_map: &asr::settings::Map, key: &str) -> T {
+    settings_map.get(key).and_then(|v| v.get_string()).and_then(|s| options_value::<T>(&s)).unwrap_or_default()
+}
+
+fn option_matches<T>(o: &RadioButtonOption<'static, T>, filter: &str) -> bool {
+    o.key.to_lowercase().contains(filter) || o.description.to_lowercase().contains(filter)
+}
+
+fn option_category<T>(o: &RadioButtonOption<'static, T>) -> &'static str {
+    o.category.unwrap_or(OTHER_CATEGORY)
+}
+
+/// The categories of the options, in the order they first appear
+fn categories<T: RadioButtonOptions>() -> Vec<&'static str> {
+    let mut categories: Vec<&'static str> = Vec::new();
+    for o in T::radio_button_options().iter().filter(|o| !o.hidden) {
+        let c = option_category(o);
+        if !categories.contains(&c) {
+            categories.push(c);
+        }
+    }
+    categories
+}
+
+fn value_category<T: RadioButtonOptions>(v: &T) -> &'static str {
+    let s = options_str(v);
+    T::radio_button_options().iter().find(|o| o.key == s).map_or(OTHER_CATEGORY, option_category)
+}
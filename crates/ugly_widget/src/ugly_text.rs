@@ -0,0 +1,46 @@
+use asr::settings::gui::{add_title, Widget};
+
+use crate::store::StoreWidget;
+use crate::ugly_list::ListItemValue;
+
+use super::args::SetHeadingLevel;
+
+// --------------------------------------------------------
+
+#[derive(Clone, Default, SetHeadingLevel)]
+#[non_exhaustive]
+pub struct UglyTextArgs {
+    pub heading_level: u32,
+}
+
+/// A string that is shown as a title, since there is no text input,
+/// and is set by editing the settings outside of the settings GUI
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct UglyText(pub String);
+
+impl Widget for UglyText {
+    type Args = UglyTextArgs;
+
+    fn register(key: &str, description: &str, args: Self::Args) -> Self {
+        add_title(key, description, args.heading_level);
+        let mut t = UglyText::default();
+        t.update_from(&asr::settings::Map::load(), key, args);
+        t
+    }
+
+    fn update_from(&mut self, settings_map: &asr::settings::Map, key: &str, _args: Self::Args) {
+        self.0 = settings_map.get(key).and_then(|v| v.get_string()).unwrap_or_default();
+    }
+}
+
+impl ListItemValue for UglyText {}
+
+impl StoreWidget for UglyText {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        if settings_map.get(key).is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == self.0)) {
+            return false;
+        }
+        settings_map.insert(key, self.0.as_str());
+        true
+    }
+}
@@ -1,6 +1,6 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Data, DataEnum, Variant, Meta, Expr, ExprLit, Lit, Attribute};
+use syn::{parse_macro_input, parse_quote, DeriveInput, Data, DataEnum, DataStruct, Variant, Field, Fields, Ident, Meta, Expr, ExprLit, Lit, Attribute, Type, TypePath};
 
 #[proc_macro_derive(SetHeadingLevel)]
 pub fn set_heading_level_derive(input: TokenStream) -> TokenStream {
@@ -27,7 +27,7 @@ fn impl_set_heading_level(ast: &DeriveInput) -> TokenStream {
     gen.into()
 }
 
-#[proc_macro_derive(RadioButtonOptions)]
+#[proc_macro_derive(RadioButtonOptions, attributes(category, deprecated_option, hidden))]
 pub fn radio_button_options_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
     let ast = parse_macro_input!(input as DeriveInput);
@@ -48,8 +48,8 @@ fn impl_radio_button_options(ast: &DeriveInput) -> TokenStream {
     };
 
     let options = variants.into_iter().map(|v| {
-        let v_name = &v.ident;
-        let v_str = v_name.to_string();
+        let v_str = v.ident.to_string();
+        let value = variant_default_value(name, v);
         let (doc_string, tooltip_string) = attrs_description_tooltip(&v.attrs);
         let desc_str =  if doc_string.is_empty() {
             v_str.clone()
@@ -61,8 +61,22 @@ fn impl_radio_button_options(ast: &DeriveInput) -> TokenStream {
         } else {
             quote! { Some( #tooltip_string ) }
         };
+        let maybe_category = match attrs_category(&v.attrs) {
+            None => quote! { None },
+            Some(category) => quote! { Some( #category ) },
+        };
+        let deprecated = attrs_flag(&v.attrs, "deprecated_option");
+        let hidden = attrs_flag(&v.attrs, "hidden");
         quote! {
-            ::ugly_widget::radio_button::RadioButtonOption { value: #name::#v_name, key: #v_str, description: #desc_str, tooltip: #maybe_tooltip }
+            ::ugly_widget::radio_button::RadioButtonOption {
+                value: #value,
+                key: #v_str,
+                description: #desc_str,
+                tooltip: #maybe_tooltip,
+                category: #maybe_category,
+                deprecated: #deprecated,
+                hidden: #hidden,
+            }
         }
     });
 
@@ -76,8 +90,288 @@ fn impl_radio_button_options(ast: &DeriveInput) -> TokenStream {
     gen.into()
 }
 
+#[proc_macro_derive(StoreWidget)]
+pub fn store_widget_derive(input: TokenStream) -> TokenStream {
+    // Parse the input tokens into a syntax tree
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    // Build the trait implementation
+    impl_store_widget(&ast).into()
+}
+
+/// For enums that implement RadioButtonOptions, stores the key of the option as a string,
+/// which is only the whole value for enums without data in their variants.
+/// For structs, stores each field under `{key}_{field}`, except `Title` fields,
+/// and all of them together as a map under `key`, so that the struct can be an item in a list.
+fn impl_store_widget(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    // Used in the quasi-quotation below as `#name`.
+    let name = &ast.ident;
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let body = match &ast.data {
+        Data::Enum(e) => {
+            // only the key of the option would be stored, dropping the data of the variant
+            if let Some(v) = e.variants.iter().find(|v| !matches!(v.fields, Fields::Unit)) {
+                return syn::Error::new_spanned(v, "StoreWidget can only be derived for enums without data in their variants").to_compile_error();
+            }
+            quote! {
+                let new_s = ::ugly_widget::radio_button::options_str(self);
+                if settings_map.get(key).is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s)) {
+                    return false;
+                }
+                settings_map.insert(key, new_s);
+                true
+            }
+        }
+        Data::Struct(DataStruct { fields: Fields::Named(fs), .. }) => {
+            let fields: Vec<&Field> = fs.named.iter().filter(|f| !is_title(f)).collect();
+            let f_strs = fields.iter().map(|f| field_str(f));
+            let inserts = fields.iter().map(|f| {
+                let f_name = &f.ident;
+                let f_str = field_str(f);
+                quote! {
+                    ::ugly_widget::store::StoreWidget::insert_into(&self.#f_name, settings_map, &format!("{}_{}", key, #f_str))
+                }
+            });
+            quote! {
+                let l = [ #(#inserts ,)* ];
+                let changed = l.into_iter().any(|b| b);
+                ::ugly_widget::store::insert_fields_map(settings_map, key, &[ #(#f_strs),* ], changed)
+            }
+        }
+        _ => {
+            return syn::Error::new_spanned(name, "StoreWidget can only be derived for enums and structs with named fields").to_compile_error();
+        }
+    };
+
+    quote! {
+        impl #impl_generics ::ugly_widget::store::StoreWidget for #name #ty_generics #where_clause {
+            fn insert_into(&self, settings_map: &::asr::settings::Map, key: &str) -> bool {
+                #body
+            }
+        }
+    }
+}
+
+#[proc_macro_derive(Widget, attributes(default, heading_level))]
+pub fn widget_derive(input: TokenStream) -> TokenStream {
+    // Parse the input tokens into a syntax tree
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    // Build the trait implementation
+    impl_widget(&ast).into()
+}
+
+/// Registers a struct as a title with each field under `{key}_{field}`,
+/// with the same doc comments and attributes as the Gui derive,
+/// except that `#[heading_level = n]` is relative to the level below the struct's title.
+/// Fields missing from the settings are filled in from a map under `key`, such as an item loaded from a list.
+/// As an item of an UglyList, moving to another index sets each field with its own ListItemValue,
+/// so that fields keeping state about their keys keep it.
+fn impl_widget(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    // Used in the quasi-quotation below as `#name`.
+    let name = &ast.ident;
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let Data::Struct(DataStruct { fields: Fields::Named(fs), .. }) = &ast.data else {
+        return syn::Error::new_spanned(name, "Widget can only be derived for structs with named fields").to_compile_error();
+    };
+
+    let f_strs = fs.named.iter().filter(|f| !is_title(f)).map(field_str);
+    let registers = fs.named.iter().map(|f| {
+        let f_name = &f.ident;
+        let f_str = field_str(f);
+        let ty = &f.ty;
+        let f_args = field_args(f);
+        let (description, tooltip) = attrs_description_tooltip(&f.attrs);
+        let maybe_tooltip = if tooltip.is_empty() {
+            quote! {}
+        } else {
+            quote! { ::asr::settings::gui::set_tooltip(&key_f, #tooltip); }
+        };
+        quote! {
+            #f_name: {
+                let key_f = format!("{}_{}", key, #f_str);
+                let v = <#ty as ::asr::settings::gui::Widget>::register(&key_f, #description, #f_args);
+                #maybe_tooltip
+                v
+            }
+        }
+    });
+    let updates = fs.named.iter().map(|f| {
+        let f_name = &f.ident;
+        let f_str = field_str(f);
+        let f_args = field_args(f);
+        quote! {
+            ::asr::settings::gui::Widget::update_from(&mut self.#f_name, settings_map, &format!("{}_{}", key, #f_str), #f_args);
+        }
+    });
+
+    let set_values = fs.named.iter().filter(|f| !is_title(f)).map(|f| {
+        let f_name = &f.ident;
+        quote! {
+            ::ugly_widget::ugly_list::ListItemValue::set_value_from(&mut self.#f_name, &other.#f_name);
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::asr::settings::gui::Widget for #name #ty_generics #where_clause {
+            type Args = ::ugly_widget::args::StructArgs;
+
+            fn register(key: &str, description: &str, args: Self::Args) -> Self {
+                ::asr::settings::gui::add_title(key, description, args.heading_level);
+                Self { #(#registers ,)* }
+            }
+
+            fn update_from(&mut self, settings_map: &::asr::settings::Map, key: &str, args: Self::Args) {
+                ::ugly_widget::store::expand_fields_map(settings_map, key, &[ #(#f_strs),* ]);
+                #(#updates)*
+            }
+        }
+
+        impl #impl_generics ::ugly_widget::ugly_list::ListItemValue for #name #ty_generics #where_clause {
+            fn set_value_from(&mut self, other: &Self) {
+                #(#set_values)*
+            }
+        }
+    }
+}
+
+/// The Args of the field's widget, with its heading level below the struct's title, and its `#[default = ...]`
+fn field_args(f: &Field) -> proc_macro2::TokenStream {
+    let ty = &f.ty;
+    let mut heading_level = quote! { args.heading_level + 1 };
+    let mut sets = vec![];
+    for attr in f.attrs.iter() {
+        let Meta::NameValue(nv) = &attr.meta else { continue; };
+        let v = &nv.value;
+        if nv.path.is_ident("heading_level") {
+            heading_level = quote! { args.heading_level + 1 + #v };
+        } else if nv.path.is_ident("default") {
+            sets.push(quote! { f_args.default = #v; });
+        }
+    }
+    quote! {
+        {
+            let mut f_args = <#ty as ::asr::settings::gui::Widget>::Args::default();
+            ::ugly_widget::args::SetHeadingLevel::set_heading_level(&mut f_args, #heading_level);
+            #(#sets)*
+            f_args
+        }
+    }
+}
+
+fn field_str(f: &Field) -> String {
+    f.ident.as_ref().map(Ident::to_string).unwrap_or_default()
+}
+
+#[proc_macro_derive(StoreGui, attributes(store_with))]
+pub fn store_gui_derive(input: TokenStream) -> TokenStream {
+    // Parse the input tokens into a syntax tree
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    // Build the trait implementation
+    impl_store_gui(&ast).into()
+}
+
+/// Stores each field of a struct that also derives Gui under the field name, the same key as the Gui derive,
+/// except `Title` fields, which have nothing to store.
+/// A field with `#[store_with = "method"]` stores the result of `self.method(settings_map)` instead.
+/// A `History` field is also used to undo and redo changes.
+fn impl_store_gui(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    // Used in the quasi-quotation below as `#name`.
+    let name = &ast.ident;
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let Data::Struct(DataStruct { fields: Fields::Named(fs), .. }) = &ast.data else {
+        return syn::Error::new_spanned(name, "StoreGui can only be derived for structs with named fields").to_compile_error();
+    };
+
+    let inserts = fs.named.iter().filter(|f| !is_title(f)).map(|f| {
+        let f_name = &f.ident;
+        let f_str = f_name.as_ref().map(Ident::to_string).unwrap_or_default();
+        let value = match attrs_store_with(&f.attrs) {
+            None => quote! { &self.#f_name },
+            Some(method) => quote! { &self.#method(settings_map) },
+        };
+        quote! {
+            ::ugly_widget::store::StoreWidget::insert_into(#value, settings_map, #f_str)
+        }
+    });
+
+    // the History only looks at the settings of the other fields
+    let owned = fs.named.iter().filter(|f| !is_title(f) && !is_type_named(f, "History")).map(|f| {
+        f.ident.as_ref().map(Ident::to_string).unwrap_or_default()
+    });
+    let maybe_history = fs.named.iter().find(|f| is_type_named(f, "History")).map(|f| {
+        let f_name = &f.ident;
+        quote! {
+            fn history(&mut self) -> Option<&mut ::ugly_widget::history::History> {
+                Some(&mut self.#f_name)
+            }
+
+            fn owns_key(key: &str) -> bool {
+                ::ugly_widget::store::key_in_fields(key, &[ #(#owned),* ])
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::ugly_widget::store::StoreGui for #name #ty_generics #where_clause {
+            fn insert_into(&self, settings_map: &::asr::settings::Map) -> bool {
+                let l = [ #(#inserts ,)* ];
+                l.into_iter().any(|b| b)
+            }
+
+            #maybe_history
+        }
+    }
+}
+
+fn is_title(f: &Field) -> bool {
+    is_type_named(f, "Title")
+}
+
+/// Whether the type of the field is a path ending in `name`
+fn is_type_named(f: &Field, name: &str) -> bool {
+    let Type::Path(TypePath { path, .. }) = &f.ty else { return false; };
+    path.segments.last().is_some_and(|s| s.ident == name)
+}
+
+/// The method from a `#[store_with = "method"]` attribute
+fn attrs_store_with(attrs: &[Attribute]) -> Option<Ident> {
+    attrs.iter().find_map(|attr| {
+        let Meta::NameValue(nv) = &attr.meta else { return None; };
+        if !nv.path.is_ident("store_with") { return None; }
+        let Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) = &nv.value else { return None; };
+        s.parse().ok()
+    })
+}
+
+/// The value of the variant, with any fields it has set to their defaults
+fn variant_default_value(name: &Ident, v: &Variant) -> Expr {
+    let v_name = &v.ident;
+    match &v.fields {
+        Fields::Unit => parse_quote! { #name::#v_name },
+        Fields::Unnamed(fs) => {
+            let defaults = fs.unnamed.iter().map(|_| quote! { ::core::default::Default::default() });
+            parse_quote! { #name::#v_name( #(#defaults),* ) }
+        }
+        Fields::Named(fs) => {
+            let defaults = fs.named.iter().map(|f| {
+                let f_name = &f.ident;
+                quote! { #f_name: ::core::default::Default::default() }
+            });
+            parse_quote! { #name::#v_name { #(#defaults),* } }
+        }
+    }
+}
+
 fn attrs_description_tooltip(attrs: &[Attribute]) -> (String, String) {
-    let lines: Vec<String> = attrs.into_iter().filter_map(|attr| {
+    let lines: Vec<String> = attrs.iter().filter_map(|attr| {
         let Meta::NameValue(nv) = &attr.meta else { return None; };
         let ident = nv.path.get_ident()?;
         if ident != "doc" { return None; }
@@ -95,6 +389,23 @@ fn attrs_description_tooltip(attrs: &[Attribute]) -> (String, String) {
     }
 }
 
+/// The string from a `#[category = "..."]` attribute
+fn attrs_category(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| {
+        let Meta::NameValue(nv) = &attr.meta else { return None; };
+        if !nv.path.is_ident("category") { return None; }
+        let Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) = &nv.value else { return None; };
+        Some(s.value())
+    })
+}
+
+/// Whether there is a `#[name]` attribute
+fn attrs_flag(attrs: &[Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| {
+        matches!(&attr.meta, Meta::Path(path) if path.is_ident(name))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use syn::parse_quote;
@@ -178,4 +489,267 @@ mod tests {
             "This is a tooltip, after multiple blank lines.\n\nAnd a continuation after even more blank lines.".to_string(),
         ));
     }
+
+    #[test]
+    fn attrs_category_none() {
+        let v: Variant = parse_quote! {
+            /// A thing
+            Thing
+        };
+        assert_eq!(attrs_category(&v.attrs), None);
+    }
+
+    #[test]
+    fn attrs_category_some() {
+        let v: Variant = parse_quote! {
+            /// A thing
+            ///
+            /// In a category
+            #[category = "Things"]
+            Thing
+        };
+        assert_eq!(attrs_category(&v.attrs), Some("Things".to_string()));
+        assert_eq!(attrs_description_tooltip(&v.attrs), (
+            "A thing".to_string(),
+            "In a category".to_string(),
+        ));
+    }
+
+    #[test]
+    fn attrs_flags() {
+        let v: Variant = parse_quote! {
+            /// An old thing
+            #[category = "Things"]
+            #[deprecated_option]
+            OldThing
+        };
+        assert!(attrs_flag(&v.attrs, "deprecated_option"));
+        assert!(!attrs_flag(&v.attrs, "hidden"));
+        assert!(!attrs_flag(&v.attrs, "category"));
+        let v: Variant = parse_quote! {
+            #[hidden]
+            SecretThing
+        };
+        assert!(attrs_flag(&v.attrs, "hidden"));
+        assert!(!attrs_flag(&v.attrs, "deprecated_option"));
+    }
+
+    #[test]
+    fn store_widget_enum() {
+        let ast: DeriveInput = parse_quote! {
+            enum Things { A, B }
+        };
+        assert_eq!(impl_store_widget(&ast).to_string(), quote! {
+            impl ::ugly_widget::store::StoreWidget for Things {
+                fn insert_into(&self, settings_map: &::asr::settings::Map, key: &str) -> bool {
+                    let new_s = ::ugly_widget::radio_button::options_str(self);
+                    if settings_map.get(key).is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s)) {
+                        return false;
+                    }
+                    settings_map.insert(key, new_s);
+                    true
+                }
+            }
+        }.to_string());
+    }
+
+    #[test]
+    fn store_widget_struct() {
+        let ast: DeriveInput = parse_quote! {
+            struct Thing { _title: Title, a: bool, b: UglyText }
+        };
+        assert_eq!(impl_store_widget(&ast).to_string(), quote! {
+            impl ::ugly_widget::store::StoreWidget for Thing {
+                fn insert_into(&self, settings_map: &::asr::settings::Map, key: &str) -> bool {
+                    let l = [
+                        ::ugly_widget::store::StoreWidget::insert_into(&self.a, settings_map, &format!("{}_{}", key, "a")),
+                        ::ugly_widget::store::StoreWidget::insert_into(&self.b, settings_map, &format!("{}_{}", key, "b")),
+                    ];
+                    let changed = l.into_iter().any(|b| b);
+                    ::ugly_widget::store::insert_fields_map(settings_map, key, &["a", "b"], changed)
+                }
+            }
+        }.to_string());
+    }
+
+    #[test]
+    fn widget_struct() {
+        let ast: DeriveInput = parse_quote! {
+            struct Thing {
+                /// Enabled
+                ///
+                /// Whether the thing is on
+                #[default = true]
+                enabled: bool,
+                /// Name
+                #[heading_level = 1]
+                name: UglyText,
+            }
+        };
+        assert_eq!(impl_widget(&ast).to_string(), quote! {
+            impl ::asr::settings::gui::Widget for Thing {
+                type Args = ::ugly_widget::args::StructArgs;
+
+                fn register(key: &str, description: &str, args: Self::Args) -> Self {
+                    ::asr::settings::gui::add_title(key, description, args.heading_level);
+                    Self {
+                        enabled: {
+                            let key_f = format!("{}_{}", key, "enabled");
+                            let v = <bool as ::asr::settings::gui::Widget>::register(&key_f, "Enabled", {
+                                let mut f_args = <bool as ::asr::settings::gui::Widget>::Args::default();
+                                ::ugly_widget::args::SetHeadingLevel::set_heading_level(&mut f_args, args.heading_level + 1);
+                                f_args.default = true;
+                                f_args
+                            });
+                            ::asr::settings::gui::set_tooltip(&key_f, "Whether the thing is on");
+                            v
+                        },
+                        name: {
+                            let key_f = format!("{}_{}", key, "name");
+                            let v = <UglyText as ::asr::settings::gui::Widget>::register(&key_f, "Name", {
+                                let mut f_args = <UglyText as ::asr::settings::gui::Widget>::Args::default();
+                                ::ugly_widget::args::SetHeadingLevel::set_heading_level(&mut f_args, args.heading_level + 1 + 1);
+                                f_args
+                            });
+                            v
+                        },
+                    }
+                }
+
+                fn update_from(&mut self, settings_map: &::asr::settings::Map, key: &str, args: Self::Args) {
+                    ::ugly_widget::store::expand_fields_map(settings_map, key, &["enabled", "name"]);
+                    ::asr::settings::gui::Widget::update_from(&mut self.enabled, settings_map, &format!("{}_{}", key, "enabled"), {
+                        let mut f_args = <bool as ::asr::settings::gui::Widget>::Args::default();
+                        ::ugly_widget::args::SetHeadingLevel::set_heading_level(&mut f_args, args.heading_level + 1);
+                        f_args.default = true;
+                        f_args
+                    });
+                    ::asr::settings::gui::Widget::update_from(&mut self.name, settings_map, &format!("{}_{}", key, "name"), {
+                        let mut f_args = <UglyText as ::asr::settings::gui::Widget>::Args::default();
+                        ::ugly_widget::args::SetHeadingLevel::set_heading_level(&mut f_args, args.heading_level + 1 + 1);
+                        f_args
+                    });
+                }
+            }
+
+            impl ::ugly_widget::ugly_list::ListItemValue for Thing {
+                fn set_value_from(&mut self, other: &Self) {
+                    ::ugly_widget::ugly_list::ListItemValue::set_value_from(&mut self.enabled, &other.enabled);
+                    ::ugly_widget::ugly_list::ListItemValue::set_value_from(&mut self.name, &other.name);
+                }
+            }
+        }.to_string());
+    }
+
+    #[test]
+    fn store_widget_enum_with_data() {
+        let ast: DeriveInput = parse_quote! {
+            enum Things { A, B(bool) }
+        };
+        assert!(impl_store_widget(&ast).to_string().contains("compile_error"));
+    }
+
+    #[test]
+    fn store_widget_tuple_struct() {
+        let ast: DeriveInput = parse_quote! {
+            struct Thing(bool);
+        };
+        assert!(impl_store_widget(&ast).to_string().contains("compile_error"));
+    }
+
+    #[test]
+    fn store_gui_struct() {
+        let ast: DeriveInput = parse_quote! {
+            struct Settings {
+                /// General
+                _general: Title,
+                /// A
+                #[default = true]
+                a: bool,
+                /// Export
+                #[heading_level = 1]
+                #[store_with = "export"]
+                export: UglyText,
+                /// Things
+                things: UglyList<Thing>,
+            }
+        };
+        assert_eq!(impl_store_gui(&ast).to_string(), quote! {
+            impl ::ugly_widget::store::StoreGui for Settings {
+                fn insert_into(&self, settings_map: &::asr::settings::Map) -> bool {
+                    let l = [
+                        ::ugly_widget::store::StoreWidget::insert_into(&self.a, settings_map, "a"),
+                        ::ugly_widget::store::StoreWidget::insert_into(&self.export(settings_map), settings_map, "export"),
+                        ::ugly_widget::store::StoreWidget::insert_into(&self.things, settings_map, "things"),
+                    ];
+                    l.into_iter().any(|b| b)
+                }
+            }
+        }.to_string());
+    }
+
+    #[test]
+    fn store_gui_history() {
+        let ast: DeriveInput = parse_quote! {
+            struct Settings {
+                /// A
+                a: bool,
+                /// History
+                history: History,
+            }
+        };
+        assert_eq!(impl_store_gui(&ast).to_string(), quote! {
+            impl ::ugly_widget::store::StoreGui for Settings {
+                fn insert_into(&self, settings_map: &::asr::settings::Map) -> bool {
+                    let l = [
+                        ::ugly_widget::store::StoreWidget::insert_into(&self.a, settings_map, "a"),
+                        ::ugly_widget::store::StoreWidget::insert_into(&self.history, settings_map, "history"),
+                    ];
+                    l.into_iter().any(|b| b)
+                }
+
+                fn history(&mut self) -> Option<&mut ::ugly_widget::history::History> {
+                    Some(&mut self.history)
+                }
+
+                fn owns_key(key: &str) -> bool {
+                    ::ugly_widget::store::key_in_fields(key, &["a"])
+                }
+            }
+        }.to_string());
+    }
+
+    #[test]
+    fn variant_value_unit() {
+        let name: Ident = parse_quote! { Things };
+        let v: Variant = parse_quote! {
+            Thing
+        };
+        let value = variant_default_value(&name, &v);
+        assert_eq!(quote!(#value).to_string(), quote!(Things::Thing).to_string());
+    }
+
+    #[test]
+    fn variant_value_unnamed() {
+        let name: Ident = parse_quote! { Things };
+        let v: Variant = parse_quote! {
+            Thing(u32, String)
+        };
+        let value = variant_default_value(&name, &v);
+        assert_eq!(quote!(#value).to_string(), quote!(
+            Things::Thing(::core::default::Default::default(), ::core::default::Default::default())
+        ).to_string());
+    }
+
+    #[test]
+    fn variant_value_named() {
+        let name: Ident = parse_quote! { Things };
+        let v: Variant = parse_quote! {
+            Thing { a: u32 }
+        };
+        let value = variant_default_value(&name, &v);
+        assert_eq!(quote!(#value).to_string(), quote!(
+            Things::Thing { a: ::core::default::Default::default() }
+        ).to_string());
+    }
 }
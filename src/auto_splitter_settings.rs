@@ -68,22 +68,22 @@ impl Settings for SettingsObject {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct XMLSettings {
     name: Option<String>,
     children: Vec<XMLNode>,
     list_items: Vec<(String, String)>,
 }
 
-impl Default for XMLSettings {
-    fn default() -> Self { XMLSettings { name: None, children: vec![], list_items: Vec::new() } }
-}
-
 impl XMLSettings {
     pub fn from_xml_string(s: &str, list_items: &[(&str, &str)]) -> Result<Self, xmltree::ParseError> {
-        let list_items = list_items.into_iter().map(|(l, i)| (l.to_string(), i.to_string())).collect();
+        let list_items = list_items.iter().map(|(l, i)| (l.to_string(), i.to_string())).collect();
         Ok(XMLSettings { name: None, children: Element::parse_all(s.as_bytes())?, list_items })
     }
+    pub fn from_xml_element(e: &Element, list_items: &[(&str, &str)]) -> Self {
+        let list_items = list_items.iter().map(|(l, i)| (l.to_string(), i.to_string())).collect();
+        XMLSettings { name: Some(e.name.clone()), children: e.children.clone(), list_items }
+    }
     fn is_list_get_item_name(&self) -> Option<&str> {
         let n = self.name.as_deref()?;
         for (l, i) in self.list_items.iter() {
@@ -166,7 +166,7 @@ fn maybe_asr_settings_map_merge<S: Settings>(old: Option<asr::settings::Map>, ne
     }
     for key in keys {
         if let Some(new_v) = new.dict_get(&key) {
-            om.insert(&key, &maybe_asr_settings_value_merge(om.get(&key), &new_v));
+            om.insert(&key, maybe_asr_settings_value_merge(om.get(&key), &new_v));
         }
     }
     om
@@ -195,14 +195,14 @@ fn maybe_asr_settings_list_merge<S: Settings>(old: Option<asr::settings::List>,
         // same length, merge elements
         let ml = asr::settings::List::new();
         for (i, ne) in new.into_iter().enumerate() {
-            ml.push(&maybe_asr_settings_value_merge(ol.get(i as u64), &ne));
+            ml.push(maybe_asr_settings_value_merge(ol.get(i as u64), &ne));
         }
         ml
     } else {
         // different length, replace the whole thing
         let ml = asr::settings::List::new();
         for ne in new.into_iter() {
-            ml.push(&maybe_asr_settings_value_merge(None, &ne));
+            ml.push(maybe_asr_settings_value_merge(None, &ne));
         }
         ml
     }
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use crate::lss::LssSplits;
+use crate::splits::Split;
+
+/// A category in the format of the category files of https://hksplitmaker.com/
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HKSplitMakerCategory {
+    pub category_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_triggering_autosplit: Option<String>,
+    /// The splits after the one that starts the timer
+    pub split_ids: Vec<String>,
+    /// Segment names for split ids, where `%s` is replaced by the default name of the split
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub names: BTreeMap<String, SegmentNames>,
+    /// Whether the last split ends the run, otherwise an ending split is added after it
+    #[serde(default)]
+    pub end_triggering_autosplit: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game_name: Option<String>,
+}
+
+/// Either one name for every time a split id is used, or a name for each time
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum SegmentNames {
+    One(String),
+    Each(Vec<String>),
+}
+
+impl HKSplitMakerCategory {
+    pub fn from_json_string(s: &str) -> Option<HKSplitMakerCategory> {
+        serde_json::from_str(s).ok()
+    }
+
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// The splits, including the start split and the ending split if there is one,
+    /// and the segment names with their templates filled in
+    pub fn to_lss_splits(&self) -> LssSplits {
+        let start = self.start_triggering_autosplit.as_deref().and_then(|s| Split::from_str(s).ok()).unwrap_or(Split::StartNewGame);
+        let mut splits = vec![start];
+        let mut segment_names = vec![];
+        let mut uses: BTreeMap<&str, usize> = BTreeMap::new();
+        for id in self.split_ids.iter() {
+            let Ok(split) = Split::from_str(id) else {
+                asr::print_message(&format!("WARNING: unknown hksplitmaker split id: {}", id));
+                continue;
+            };
+            let i = uses.entry(id).or_default();
+            let template = match self.names.get(id) {
+                Some(SegmentNames::One(t)) => Some(t.as_str()),
+                Some(SegmentNames::Each(ts)) => ts.get(*i).map(String::as_str),
+                None => None,
+            };
+            *i += 1;
+            let default_name = split.default_segment_name();
+            segment_names.push(template.map(|t| t.replace("%s", &default_name)).unwrap_or(default_name));
+            splits.push(split);
+        }
+        if !self.end_triggering_autosplit {
+            splits.push(Split::EndingSplit);
+            segment_names.push(Split::EndingSplit.default_segment_name());
+        }
+        LssSplits { segment_names, splits }
+    }
+
+    /// A category for the splits, with name templates for any segment names that differ from the defaults,
+    /// if there is one segment name for each split after the start split
+    pub fn from_splits(category_name: &str, splits: &[Split], segment_names: &[String]) -> HKSplitMakerCategory {
+        let Some((start, rest)) = splits.split_first() else {
+            return HKSplitMakerCategory { category_name: category_name.to_string(), ..Default::default() };
+        };
+        let start_triggering_autosplit = if start == &Split::StartNewGame { None } else { Some(start.to_string()) };
+        let end_triggering_autosplit = rest.last() != Some(&Split::EndingSplit);
+        let names_used = if segment_names.len() == rest.len() { segment_names } else { &[] };
+        let rest = if end_triggering_autosplit { rest } else { &rest[..rest.len() - 1] };
+        let split_ids: Vec<String> = rest.iter().map(Split::to_string).collect();
+        let mut templates: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (i, split) in rest.iter().enumerate() {
+            let default_name = split.default_segment_name();
+            let template = match names_used.get(i) {
+                Some(name) if name.contains(&default_name) => name.replacen(&default_name, "%s", 1),
+                Some(name) => name.clone(),
+                None => "%s".to_string(),
+            };
+            templates.entry(split_ids[i].clone()).or_default().push(template);
+        }
+        let names = templates.into_iter().filter_map(|(id, ts)| {
+            if ts.iter().any(|t| t != &ts[0]) {
+                Some((id, SegmentNames::Each(ts)))
+            } else if ts[0] != "%s" {
+                Some((id, SegmentNames::One(ts[0].clone())))
+            } else {
+                None
+            }
+        }).collect();
+        HKSplitMakerCategory {
+            category_name: category_name.to_string(),
+            start_triggering_autosplit,
+            split_ids,
+            names,
+            end_triggering_autosplit,
+            game_name: None,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hksplitmaker_json_to_lss_splits() {
+        let category = HKSplitMakerCategory::from_json_string(r#"{
+            "categoryName": "Grubs",
+            "startTriggeringAutosplit": "StartAnyGame",
+            "splitIds": ["Grub1", "Grub2", "Hornet1", "Grub2"],
+            "names": {"Grub1": "%s - Acid", "Grub2": ["First %s", "Second %s"]},
+            "endTriggeringAutosplit": false
+        }"#).expect("category should parse");
+        let lss_splits = category.to_lss_splits();
+        assert!(lss_splits.is_valid());
+        assert_eq!(lss_splits.splits, [Split::StartAnyGame, Split::Grub1, Split::Grub2, Split::Hornet1, Split::Grub2, Split::EndingSplit]);
+        let grub1 = Split::Grub1.default_segment_name();
+        let grub2 = Split::Grub2.default_segment_name();
+        assert_eq!(lss_splits.segment_names, [
+            format!("{} - Acid", grub1),
+            format!("First {}", grub2),
+            Split::Hornet1.default_segment_name(),
+            format!("Second {}", grub2),
+            Split::EndingSplit.default_segment_name(),
+        ]);
+    }
+
+    #[test]
+    fn hksplitmaker_round_trip() {
+        let splits = [Split::StartNewGame, Split::Grub1, Split::Hornet1, Split::Grub1, Split::EndingSplit];
+        let segment_names: Vec<String> = ["Acid Grub", "Hornet", "Spikes Grub", "The End"].into_iter().map(String::from).collect();
+        let json = HKSplitMakerCategory::from_splits("Grubs", &splits, &segment_names).to_json_string();
+        let category = HKSplitMakerCategory::from_json_string(&json).expect("exported category should parse");
+        assert_eq!(category.category_name, "Grubs");
+        let lss_splits = category.to_lss_splits();
+        assert_eq!(lss_splits.splits, splits);
+        assert_eq!(lss_splits.segment_names[..3], segment_names[..3]);
+        // the ending split isn't in hksplitmaker categories, so its segment gets the default name
+        assert_eq!(lss_splits.segment_names[3], Split::EndingSplit.default_segment_name());
+        let four_mask_shards = HKSplitMakerCategory::from_json_string(include_str!("../hksplitmaker.json")).expect("hksplitmaker.json should parse");
+        let lss_splits = four_mask_shards.to_lss_splits();
+        let again = HKSplitMakerCategory::from_splits(&four_mask_shards.category_name, &lss_splits.splits, &lss_splits.segment_names).to_lss_splits();
+        assert_eq!(again.splits, lss_splits.splits);
+        assert_eq!(again.segment_names, lss_splits.segment_names);
+    }
+}
@@ -1,5 +1,5 @@
 
-use core::cell::OnceCell;
+use core::cell::{OnceCell, RefCell};
 use std::cmp::min;
 use std::mem;
 use std::collections::BTreeMap;
@@ -10,6 +10,8 @@ use asr::game_engine::unity::mono::{self, UnityPointer};
 use asr::string::ArrayWString;
 use ugly_widget::store::StoreGui;
 
+use crate::scene_table::{self, MapZone};
+
 #[cfg(debug_assertions)]
 use std::string::String;
 
@@ -26,7 +28,7 @@ pub const SCENE_PATH_SIZE: usize = 64;
 const STRING_LEN_OFFSET: u64 = 0x10;
 const STRING_CONTENTS_OFFSET: u64 = 0x14;
 
-const PRE_MENU_INTRO: &str = "Pre_Menu_Intro";
+pub const PRE_MENU_INTRO: &str = "Pre_Menu_Intro";
 pub const MENU_TITLE: &str = "Menu_Title";
 pub const QUIT_TO_MENU: &str = "Quit_To_Menu";
 pub const PERMA_DEATH: &str = "PermaDeath";
@@ -38,31 +40,12 @@ pub const OPENING_SCENES: [&str; 2] = [
     OPENING_SEQUENCE,
 ];
 
-const NON_PLAY_SCENES: [&str; 16] = [
-    PRE_MENU_INTRO,
-    MENU_TITLE,
-    QUIT_TO_MENU,
-    INTRO_CUTSCENE,
-    OPENING_SEQUENCE,
-    GG_ENTRANCE_CUTSCENE,
-    "Cinematic_Ending_A",
-    "Cinematic_Ending_B",
-    "Cinematic_Ending_C",
-    "Cinematic_Ending_D",
-    "Cinematic_Ending_E",
-    "End_Credits",
-    "Cinematic_MrMushroom",
-    "End_Game_Completion",
-    PERMA_DEATH,
-    "PermaDeath_Unlock",
-];
-
 const DEBUG_SAVE_STATE_SCENE_NAMES: [&str; 2] = [
     "Room_Mender_House",
     "Room_Sly_Storeroom",
 ];
 
-const BAD_SCENE_NAMES: [&str; 11] = [
+pub const BAD_SCENE_NAMES: [&str; 11] = [
     "Untagged",
     "left1",
     "oncomplete",
@@ -76,43 +59,6 @@ const BAD_SCENE_NAMES: [&str; 11] = [
     "material",
 ];
 
-pub const FUNGAL_WASTES_ENTRY_SCENES: &[&str] = &[
-    "Fungus2_06", // Room outside Leg Eater
-    "Fungus2_03", // From Queens' Station
-    "Fungus2_23", // Bretta from Waterways
-    "Fungus2_20", // Spore Shroom room, from QG (this one's unlikely to come up)
-];
-
-pub const CRYSTAL_PEAK_ENTRY_SCENES: &[&str] = &[
-    "Mines_02",
-    "Mines_10",
-];
-
-pub const WATERWAYS_ENTRY_SCENES: &[&str] = &[
-    "Waterways_01", // Simple Key manhole entrance
-    // Note: Waterways_06 does not show the Area Text
-    "Waterways_07", // Where the Spike-tunnel and KE-Tram-CDash entrances meet
-];
-
-pub const FOG_CANYON_ENTRY_SCENES: &[&str] = &[
-    "Fungus3_01", // West Fog Canyon entrance from Greenpath
-    "Fungus3_02", // West Fog Canyon entrance from Queen's Station or QGA
-    "Fungus3_24", // West Fog Canyon entrance from Queen's Gardens via Overgrown Mound
-    "Fungus3_26", // East Fog Canyon, where the Crossroads acid and Leg Eater acid entrances meet
-];
-
-pub const QUEENS_GARDENS_ENTRY_SCENES: &[&str] = &[
-    "Fungus3_34",
-    "Deepnest_43",
-];
-
-pub const DEEPNEST_ENTRY_SCENES: &[&str] = &[
-    "Fungus2_25", // Room after Mantis Lords
-    "Deepnest_42", // Room outside Mask Maker
-    "Abyss_03b", // Deepnest Tram
-    "Deepnest_01b", // Near Spore Shroom
-];
-
 pub const GODHOME_LORE_SCENES: &[&str] = &[
     "GG_Engine", // includes GG_Engine_Prime when using starts_with_any
     "GG_Unn",
@@ -149,6 +95,8 @@ struct GameManagerPointers {
     scene_name: UnityPointer<2>,
     next_scene_name: UnityPointer<2>,
     game_state: UnityPointer<2>,
+    map_zone: UnityPointer<3>,
+    player_data: UnityPointer<2>,
     ui_state_vanilla: UnityPointer<3>,
     ui_state_modded: UnityPointer<3>,
     camera_teleporting: UnityPointer<3>,
@@ -168,6 +116,8 @@ impl GameManagerPointers {
             scene_name: UnityPointer::new("GameManager", 0, &["_instance", "sceneName"]),
             next_scene_name: UnityPointer::new("GameManager", 0, &["_instance", "nextSceneName"]),
             game_state: UnityPointer::new("GameManager", 0, &["_instance", "gameState"]),
+            map_zone: UnityPointer::new("GameManager", 0, &["_instance", "sm", "mapZone"]),
+            player_data: UnityPointer::new("GameManager", 0, &["_instance", "playerData"]),
             ui_state_vanilla: UnityPointer::new("GameManager", 0, &["_instance", "<ui>k__BackingField", "uiState"]),
             ui_state_modded: UnityPointer::new("GameManager", 0, &["_instance", "_uiInstance", "uiState"]),
             camera_teleporting: UnityPointer::new("GameManager", 0, &["_instance", "<cameraCtrl>k__BackingField", "teleporting"]),
@@ -184,6 +134,269 @@ impl GameManagerPointers {
 
 // --------------------------------------------------------
 
+/// PlayerData int fields suggested for splits on arbitrary thresholds, though any int field can be read by name
+pub const PLAYER_DATA_INT_FIELDS: &[&str] = &[
+    "aladarSlugDefeated",
+    "charmCost_36",
+    "charmSlots",
+    "charmsOwned",
+    "dreamOrbs",
+    "elderHuDefeated",
+    "fireballLevel",
+    "flamesCollected",
+    "galienDefeated",
+    "geo",
+    "grimmChildLevel",
+    "grubsCollected",
+    "guardiansDefeated",
+    "health",
+    "heartPieces",
+    "killsGrubMimic",
+    "killsMegaBeamMiner",
+    "killsZombieMiner",
+    "markothDefeated",
+    "maxHealthBase",
+    "MPReserveMax",
+    "mrMushroomState",
+    "mumCaterpillarDefeated",
+    "nailSmithUpgrades",
+    "noEyesDefeated",
+    "ore",
+    "quakeLevel",
+    "rancidEggs",
+    "royalCharmState",
+    "screamLevel",
+    "simpleKeys",
+    "soldTrinket2",
+    "soldTrinket4",
+    "stagPosition",
+    "trinket1",
+    "trinket2",
+    "trinket3",
+    "trinket4",
+    "vesselFragments",
+    "xeroDefeated",
+];
+
+/// PlayerData bool fields suggested for splits, though any bool field can be read by name
+pub const PLAYER_DATA_BOOL_FIELDS: &[&str] = &[
+    "atBench",
+    "brettaRescued",
+    "brokenCharm_23",
+    "brokenCharm_24",
+    "collectorDefeated",
+    "colosseumBronzeCompleted",
+    "colosseumBronzeOpened",
+    "colosseumGoldCompleted",
+    "colosseumGoldOpened",
+    "colosseumSilverCompleted",
+    "colosseumSilverOpened",
+    "corniferAtHome",
+    "crossroadsInfected",
+    "defeatedMantisLords",
+    "defeatedMegaBeamMiner",
+    "destroyedNightmareLantern",
+    "disablePause",
+    "dreamNailUpgraded",
+    "elderbugGaveFlower",
+    "encounteredMegaJelly",
+    "falseKnightDreamDefeated",
+    "falseKnightOrbsCollected",
+    "fragileGreed_unbreakable",
+    "fragileHealth_unbreakable",
+    "fragileStrength_unbreakable",
+    "gladeDoorOpened",
+    "gotBrummsFlame",
+    "gotCharm_1",
+    "gotCharm_10",
+    "gotCharm_11",
+    "gotCharm_12",
+    "gotCharm_13",
+    "gotCharm_14",
+    "gotCharm_15",
+    "gotCharm_16",
+    "gotCharm_17",
+    "gotCharm_18",
+    "gotCharm_19",
+    "gotCharm_2",
+    "gotCharm_20",
+    "gotCharm_21",
+    "gotCharm_22",
+    "gotCharm_23",
+    "gotCharm_24",
+    "gotCharm_25",
+    "gotCharm_26",
+    "gotCharm_27",
+    "gotCharm_28",
+    "gotCharm_29",
+    "gotCharm_3",
+    "gotCharm_30",
+    "gotCharm_31",
+    "gotCharm_32",
+    "gotCharm_33",
+    "gotCharm_34",
+    "gotCharm_35",
+    "gotCharm_37",
+    "gotCharm_38",
+    "gotCharm_39",
+    "gotCharm_4",
+    "gotCharm_40",
+    "gotCharm_5",
+    "gotCharm_6",
+    "gotCharm_7",
+    "gotCharm_8",
+    "gotCharm_9",
+    "gotGrimmNotch",
+    "gotKingFragment",
+    "gotLurkerKey",
+    "gotQueenFragment",
+    "gotShadeCharm",
+    "greyPrinceOrbsCollected",
+    "hasAcidArmour",
+    "hasCityKey",
+    "hasCyclone",
+    "hasDash",
+    "hasDashSlash",
+    "hasDoubleJump",
+    "hasDreamGate",
+    "hasDreamNail",
+    "hasGodfinder",
+    "hasKingsBrand",
+    "hasLantern",
+    "hasLoveKey",
+    "hasShadowDash",
+    "hasSlykey",
+    "hasSuperDash",
+    "hasTramPass",
+    "hasUpwardSlash",
+    "hasWalljump",
+    "hasWhiteKey",
+    "hegemolDefeated",
+    "hornet1Defeated",
+    "hornetOutskirtsDefeated",
+    "infectedKnightDreamDefeated",
+    "infectedKnightOrbsCollected",
+    "killedBigFly",
+    "killedBlackKnight",
+    "killedDungDefender",
+    "killedFalseKnight",
+    "killedFinalBoss",
+    "killedFlukeMother",
+    "killedGhostAladar",
+    "killedGhostGalien",
+    "killedGhostHu",
+    "killedGhostMarkoth",
+    "killedGhostMarmu",
+    "killedGhostNoEyes",
+    "killedGhostXero",
+    "killedGiantHopper",
+    "killedGorgeousHusk",
+    "killedGreyPrince",
+    "killedGrimm",
+    "killedHiveKnight",
+    "killedHollowKnight",
+    "killedHollowKnightPrime",
+    "killedHornet",
+    "killedHunterMark",
+    "killedInfectedKnight",
+    "killedLazyFlyer",
+    "killedLobsterLancer",
+    "killedMageLord",
+    "killedMawlek",
+    "killedMegaJellyfish",
+    "killedMenderBug",
+    "killedMimicSpider",
+    "killedMossKnight",
+    "killedNailBros",
+    "killedNailsage",
+    "killedNightmareGrimm",
+    "killedPaintmaster",
+    "killedTraitorLord",
+    "killedWhiteDefender",
+    "killedZote",
+    "littleFoolMet",
+    "lurienDefeated",
+    "mageLordDreamDefeated",
+    "mageLordEncountered",
+    "mageLordEncountered_2",
+    "mageLordOrbsCollected",
+    "maskBrokenHegemol",
+    "maskBrokenLurien",
+    "maskBrokenMonomon",
+    "megaMossChargerDefeated",
+    "metEmilitia",
+    "metRelicDealerShop",
+    "metXun",
+    "mineLiftOpened",
+    "monomonDefeated",
+    "mothDeparted",
+    "nailsmithConvoArt",
+    "nailsmithKilled",
+    "nailsmithSpared",
+    "newDataBindingSeal",
+    "nightmareLanternLit",
+    "notchFogCanyon",
+    "notchShroomOgres",
+    "openedCityGate",
+    "openedCrossroads",
+    "openedDeepnest",
+    "openedFungalWastes",
+    "openedGreenpath",
+    "openedHiddenStation",
+    "openedRestingGrounds",
+    "openedRoyalGardens",
+    "openedRuins1",
+    "openedRuins2",
+    "openedStagNest",
+    "openedTramLower",
+    "openedWaterwaysManhole",
+    "ordealAchieved",
+    "salubraBlessing",
+    "salubraNotch1",
+    "salubraNotch2",
+    "salubraNotch3",
+    "salubraNotch4",
+    "savedCloth",
+    "seenColosseumTitle",
+    "slyRescued",
+    "slySimpleKey",
+    "spiderCapture",
+    "tollBenchAbyss",
+    "tollBenchCity",
+    "tollBenchQueensGardens",
+    "travelling",
+    "unchainedHollowKnight",
+    "visitedAbyss",
+    "visitedCrossroads",
+    "visitedDeepnest",
+    "visitedDeepnestSpa",
+    "visitedDirtmouth",
+    "visitedFogCanyon",
+    "visitedFungus",
+    "visitedGodhome",
+    "visitedGreenpath",
+    "visitedHive",
+    "visitedMines",
+    "visitedOutskirts",
+    "visitedRestingGrounds",
+    "visitedRoyalGardens",
+    "visitedRuins",
+    "visitedWaterways",
+    "visitedWhitePalace",
+    "watcherChandelier",
+    "whiteDefenderOrbsCollected",
+    "whitePalaceOrb_1",
+    "whitePalaceOrb_2",
+    "whitePalaceOrb_3",
+    "whitePalaceSecretRoomVisited",
+    "xunFlowerGiven",
+    "zoteRescuedBuzzer",
+    "zoteRescuedDeepnest",
+    "zoteStatueWallBroken",
+];
+
+// --------------------------------------------------------
+
 struct PlayerDataPointers {
     version: UnityPointer<4>,
     disable_pause: UnityPointer<3>,
@@ -240,7 +453,6 @@ struct PlayerDataPointers {
     has_kings_brand: UnityPointer<3>,
     has_tram_pass: UnityPointer<3>,
     cornifer_at_home: UnityPointer<3>,
-    #[cfg(debug_assertions)]
     geo: UnityPointer<3>,
     // Nail and Pale Ore
     nail_smith_upgrades: UnityPointer<3>,
@@ -276,6 +488,7 @@ struct PlayerDataPointers {
     got_grimm_notch: UnityPointer<3>,
     charm_slots: UnityPointer<3>,
     // Charms
+    charms_owned: UnityPointer<3>,
     got_charm_1: UnityPointer<3>,
     got_charm_2: UnityPointer<3>,
     got_charm_3: UnityPointer<3>,
@@ -360,6 +573,7 @@ struct PlayerDataPointers {
     killed_moss_knight: UnityPointer<3>,
     zote_rescued_buzzer: UnityPointer<3>,
     killed_hornet: UnityPointer<3>,
+    hornet1_defeated: UnityPointer<3>,
     /// killedLazyFlyer: Aluba
     killed_lazy_flyer: UnityPointer<3>,
     killed_hunter_mark: UnityPointer<3>,
@@ -526,7 +740,6 @@ impl PlayerDataPointers {
             has_kings_brand: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "hasKingsBrand"]),
             has_tram_pass: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "hasTramPass"]),
             cornifer_at_home: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "corniferAtHome"]),
-            #[cfg(debug_assertions)]
             geo: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "geo"]),
             // Nail and Pale Ore
             nail_smith_upgrades: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "nailSmithUpgrades"]),
@@ -562,6 +775,7 @@ impl PlayerDataPointers {
             got_grimm_notch: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "gotGrimmNotch"]),
             charm_slots: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "charmSlots"]),
             // Charms
+            charms_owned: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "charmsOwned"]),
             got_charm_1: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "gotCharm_1"]),
             got_charm_2: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "gotCharm_2"]),
             got_charm_3: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "gotCharm_3"]),
@@ -644,6 +858,7 @@ impl PlayerDataPointers {
             killed_moss_knight: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "killedMossKnight"]),
             zote_rescued_buzzer: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "zoteRescuedBuzzer"]),
             killed_hornet: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "killedHornet"]),
+            hornet1_defeated: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "hornet1Defeated"]),
             killed_lazy_flyer: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "killedLazyFlyer"]),
             killed_hunter_mark: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "killedHunterMark"]),
             killed_ghost_no_eyes: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "killedGhostNoEyes"]),
@@ -796,6 +1011,7 @@ pub struct GameManagerFinder {
     player_data_pointers: PlayerDataPointers,
     completion_pointers: CompletionPointers,
     ui_state_offset: OnceCell<u32>,
+    player_data_offsets: RefCell<BTreeMap<String, u32>>,
 }
 
 impl GameManagerFinder {
@@ -822,6 +1038,7 @@ impl GameManagerFinder {
                         player_data_pointers: PlayerDataPointers::new(),
                         completion_pointers: CompletionPointers::new(),
                         ui_state_offset: OnceCell::new(),
+                        player_data_offsets: RefCell::new(BTreeMap::new()),
                     };
                 }
                 next_tick().await;
@@ -848,7 +1065,12 @@ impl GameManagerFinder {
         self.pointers.game_state.deref(process, &self.module, &self.image).ok()
     }
 
-    fn is_game_state_non_menu(&self, process: &Process) -> bool {
+    pub fn get_map_zone(&self, process: &Process) -> Option<MapZone> {
+        let i: i32 = self.pointers.map_zone.deref(process, &self.module, &self.image).ok()?;
+        MapZone::from_i32(i)
+    }
+
+    pub fn is_game_state_non_menu(&self, process: &Process) -> bool {
         self.get_game_state(process).is_some_and(|gs| NON_MENU_GAME_STATES.contains(&gs))
     }
 
@@ -875,6 +1097,31 @@ impl GameManagerFinder {
         }
     }
 
+    fn player_data_offset(&self, process: &Process, name: &str) -> Option<u32> {
+        // save the offsets so they don't have to be found in the PlayerData class every time
+        if let Some(&offset) = self.player_data_offsets.borrow().get(name) {
+            return Some(offset);
+        }
+        let player_data_class = self.image.get_class(process, &self.module, "PlayerData")?;
+        let offset = player_data_class.get_field_offset(process, &self.module, name)?;
+        self.player_data_offsets.borrow_mut().insert(name.to_string(), offset);
+        Some(offset)
+    }
+
+    /// Reads a PlayerData int field by name, such as one of PLAYER_DATA_INT_FIELDS
+    pub fn player_data_i32_by_name(&self, process: &Process, name: &str) -> Option<i32> {
+        let offset = self.player_data_offset(process, name)?;
+        let player_data: Address64 = self.pointers.player_data.deref(process, &self.module, &self.image).ok()?;
+        process.read_pointer_path64(player_data, &[offset as u64]).ok()
+    }
+
+    /// Reads a PlayerData bool field by name, such as one of PLAYER_DATA_BOOL_FIELDS
+    pub fn player_data_bool_by_name(&self, process: &Process, name: &str) -> Option<bool> {
+        let offset = self.player_data_offset(process, name)?;
+        let player_data: Address64 = self.pointers.player_data.deref(process, &self.module, &self.image).ok()?;
+        process.read_pointer_path64(player_data, &[offset as u64]).ok()
+    }
+
     pub fn camera_teleporting(&self, process: &Process) -> Option<bool> {
         self.pointers.camera_teleporting.deref(process, &self.module, &self.image).ok()
     }
@@ -1096,7 +1343,6 @@ impl GameManagerFinder {
         self.player_data_pointers.has_tram_pass.deref(process, &self.module, &self.image).ok()
     }
 
-    #[cfg(debug_assertions)]
     pub fn get_geo(&self, process: &Process) -> Option<i32> {
         self.player_data_pointers.geo.deref(process, &self.module, &self.image).ok()
     }
@@ -1211,9 +1457,16 @@ impl GameManagerFinder {
     pub fn got_grimm_notch(&self, process: &Process) -> Option<bool> {
         self.player_data_pointers.got_grimm_notch.deref(process, &self.module, &self.image).ok()
     }
+    pub fn charm_slots(&self, process: &Process) -> Option<i32> {
+        self.player_data_pointers.charm_slots.deref(process, &self.module, &self.image).ok()
+    }
 
     // Charms
 
+    pub fn charms_owned(&self, process: &Process) -> Option<i32> {
+        self.player_data_pointers.charms_owned.deref(process, &self.module, &self.image).ok()
+    }
+
     pub fn got_charm_1(&self, process: &Process) -> Option<bool> {
         self.player_data_pointers.got_charm_1.deref(process, &self.module, &self.image).ok()
     }
@@ -1536,6 +1789,11 @@ impl GameManagerFinder {
         self.player_data_pointers.killed_hornet.deref(process, &self.module, &self.image).ok()
     }
 
+    /// hornet1Defeated: Hornet Protector in Greenpath
+    pub fn hornet1_defeated(&self, process: &Process) -> Option<bool> {
+        self.player_data_pointers.hornet1_defeated.deref(process, &self.module, &self.image).ok()
+    }
+
     /// killedLazyFlyer: Aluba
     pub fn killed_lazy_flyer(&self, process: &Process) -> Option<bool> {
         self.player_data_pointers.killed_lazy_flyer.deref(process, &self.module, &self.image).ok()
@@ -1625,10 +1883,6 @@ impl GameManagerFinder {
         self.player_data_pointers.visited_ruins.deref(process, &self.module, &self.image).ok()
     }
 
-    pub fn killed_gorgeous_husk(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers.killed_gorgeous_husk.deref(process, &self.module, &self.image).ok()
-    }
-
     // Lemm
     pub fn met_relic_dealer_shop(&self, process: &Process) -> Option<bool> {
         self.player_data_pointers.met_relic_dealer_shop.deref(process, &self.module, &self.image).ok()
@@ -1959,7 +2213,158 @@ impl GameManagerFinder {
         self.completion_pointers.boss_door_state_tier5.deref(process, &self.module, &self.image).ok()
     }
 
+    // Completion
+
+    /// The game completion percentage, out of 112%,
+    /// counted the same way as PlayerData.CountGameCompletion.
+    /// This reads about 70 PlayerData fields, so use PlayerDataStore::completion_percentage to check it every tick.
+    pub fn completion_percentage(&self, process: &Process) -> Option<i32> {
+        Some(self.completion_items(process)?.percentage())
+    }
+
+    fn completion_items(&self, process: &Process) -> Option<CompletionItems> {
+        Some(CompletionItems {
+            charms_owned: self.charms_owned(process)?,
+            bosses: [
+                self.killed_false_knight(process)?,
+                self.hornet1_defeated(process)?,
+                self.hornet_outskirts_defeated(process)?,
+                self.defeated_mantis_lords(process)?,
+                self.killed_mage_lord(process)?,
+                self.killed_dung_defender(process)?,
+                self.killed_black_knight(process)?,
+                self.killed_infected_knight(process)?,
+                self.killed_mimic_spider(process)?,
+                self.killed_mega_jellyfish(process)?,
+                self.killed_traitor_lord(process)?,
+                self.killed_grimm(process)?,
+                self.killed_nightmare_grimm(process)? || self.destroyed_nightmare_lantern(process)?,
+            ],
+            warrior_dreams: [
+                self.killed_ghost_aladar(process)?,
+                self.killed_ghost_xero(process)?,
+                self.killed_ghost_hu(process)?,
+                self.killed_ghost_marmu(process)?,
+                self.killed_ghost_no_eyes(process)?,
+                self.killed_ghost_markoth(process)?,
+                self.killed_ghost_galien(process)?,
+            ],
+            colosseum: [
+                self.colosseum_bronze_completed(process)?,
+                self.colosseum_silver_completed(process)?,
+                self.colosseum_gold_completed(process)?,
+            ],
+            dreamers: [
+                self.lurien_defeated(process)?,
+                self.monomon_defeated(process)?,
+                self.hegemol_defeated(process)?,
+            ],
+            nail_arts: [
+                self.has_cyclone(process)?,
+                self.has_dash_slash(process)?,
+                self.has_upward_slash(process)?,
+            ],
+            pantheons: [
+                self.boss_door_state_tier1(process)?.completed,
+                self.boss_door_state_tier2(process)?.completed,
+                self.boss_door_state_tier3(process)?.completed,
+                self.boss_door_state_tier4(process)?.completed,
+            ],
+            equipment: [
+                self.has_dash(process)?,
+                self.has_wall_jump(process)?,
+                self.has_super_dash(process)?,
+                self.has_double_jump(process)?,
+                self.has_acid_armour(process)?,
+                self.has_shadow_dash(process)?,
+            ],
+            dream_nail: [
+                self.has_dream_nail(process)?,
+                self.dream_nail_upgraded(process)?,
+            ],
+            nail_smith_upgrades: self.nail_smith_upgrades(process)?,
+            charm_slots: self.charm_slots(process)?,
+            max_health_base: self.max_health_base(process)?,
+            mp_reserve_max: self.mp_reserve_max(process)?,
+            fireball_level: self.get_fireball_level(process)?,
+            quake_level: self.get_quake_level(process)?,
+            scream_level: self.get_scream_level(process)?,
+        })
+    }
+}
+
+/// The PlayerData that counts towards the game completion percentage
+struct CompletionItems {
+    charms_owned: i32,
+    bosses: [bool; 13],
+    warrior_dreams: [bool; 7],
+    colosseum: [bool; 3],
+    dreamers: [bool; 3],
+    nail_arts: [bool; 3],
+    pantheons: [bool; 4],
+    /// Mothwing Cloak, Mantis Claw, Crystal Heart, Monarch Wings, Isma's Tear, and Shade Cloak
+    equipment: [bool; 6],
+    dream_nail: [bool; 2],
+    nail_smith_upgrades: i32,
+    charm_slots: i32,
+    max_health_base: i32,
+    mp_reserve_max: i32,
+    fireball_level: i32,
+    quake_level: i32,
+    scream_level: i32,
+}
+
+impl CompletionItems {
+    /// 1% for each item, except 2% for each piece of equipment, and 1% for each level of each spell up to 2,
+    /// adding up to 108% before the 4% for the pantheons
+    fn percentage(&self) -> i32 {
+        let count = |bs: &[bool]| bs.iter().filter(|&&b| b).count() as i32;
+        self.charms_owned
+            + count(&self.bosses)
+            + count(&self.warrior_dreams)
+            + count(&self.colosseum)
+            + count(&self.dreamers)
+            + count(&self.nail_arts)
+            + count(&self.pantheons)
+            + 2 * count(&self.equipment)
+            + count(&self.dream_nail)
+            + self.nail_smith_upgrades
+            + (self.charm_slots - 3)
+            + (self.max_health_base - 5)
+            + (self.mp_reserve_max / 33)
+            + min(self.fireball_level, 2)
+            + min(self.quake_level, 2)
+            + min(self.scream_level, 2)
+    }
+}
+
+/// The number of checks that a CompletionCache answers with the kept completion percentage before reading it again
+const COMPLETION_READ_CHECKS: u32 = 15;
 
+/// Keeps the game completion percentage for a few checks,
+/// so that it can be checked every tick without reading all of its PlayerData every tick,
+/// at the cost of noticing a change up to COMPLETION_READ_CHECKS checks late
+#[derive(Default)]
+struct CompletionCache {
+    percentage: Option<i32>,
+    checks_since_read: u32,
+}
+
+impl CompletionCache {
+    /// The kept completion percentage, or the one from `read` if it's time to read it again
+    fn get_or_read(&mut self, read: impl FnOnce() -> Option<i32>) -> Option<i32> {
+        if self.percentage.is_none() || COMPLETION_READ_CHECKS <= self.checks_since_read {
+            self.checks_since_read = 0;
+            self.percentage = read().or(self.percentage);
+        } else {
+            self.checks_since_read += 1;
+        }
+        self.percentage
+    }
+
+    fn reset(&mut self) {
+        *self = CompletionCache::default();
+    }
 }
 
 pub struct SceneStore {
@@ -2068,6 +2473,7 @@ impl SceneStore {
 pub struct PlayerDataStore {
     map_i32: BTreeMap<&'static str, i32>,
     map_bool: BTreeMap<&'static str, bool>,
+    completion: CompletionCache,
 }
 
 impl PlayerDataStore {
@@ -2075,11 +2481,18 @@ impl PlayerDataStore {
         PlayerDataStore { 
             map_i32: BTreeMap::new(),
             map_bool: BTreeMap::new(),
+            completion: CompletionCache::default(),
         }
     }
     pub fn reset(&mut self) {
         self.map_i32.clear();
         self.map_bool.clear();
+        self.completion.reset();
+    }
+
+    /// The game completion percentage, kept for a few checks between reads, and forgotten on reset
+    pub fn completion_percentage(&mut self, p: &Process, g: &GameManagerFinder) -> Option<i32> {
+        self.completion.get_or_read(|| g.completion_percentage(p))
     }
 
     fn get_bool<const N: usize>(&mut self, p: &Process, g: &GameManagerFinder, key: &'static str, pointer: &UnityPointer<N>) -> Option<bool> {
@@ -2164,6 +2577,21 @@ impl PlayerDataStore {
             && self.map_i32.get("vessel_fragments").is_some_and(|&f| f < 3))
     }
 
+    fn changed_map_zone(&mut self, p: &Process, g: &GameManagerFinder) -> Option<Pair<MapZone>> {
+        let current = g.get_map_zone(p).filter(|&z| z != MapZone::None)?;
+        let old = self.map_i32.insert("map_zone", current as i32).and_then(MapZone::from_i32)?;
+        if old == current { return None; }
+        Some(Pair { old, current })
+    }
+
+    pub fn entered_map_zone(&mut self, p: &Process, g: &GameManagerFinder, z: MapZone) -> bool {
+        self.changed_map_zone(p, g).is_some_and(|c| c.current == z)
+    }
+
+    pub fn left_map_zone(&mut self, p: &Process, g: &GameManagerFinder, z: MapZone) -> bool {
+        self.changed_map_zone(p, g).is_some_and(|c| c.old == z)
+    }
+
     pub fn guardians_defeated(&mut self, p: &Process, g: &GameManagerFinder) -> i32 {
         self.get_i32(p, g, "guardians_defeated", &g.player_data_pointers.guardians_defeated).unwrap_or(0)
     }
@@ -2324,9 +2752,9 @@ pub async fn wait_attach_hollow_knight<G: StoreGui>(gui: &mut G) -> Process {
 
 fn read_string_object<const N: usize>(process: &Process, a: Address64) -> Option<String> {
     let n: u32 = process.read_pointer_path64(a, &[STRING_LEN_OFFSET]).ok()?;
-    if !(n < 2048) { return None; }
+    if 2048 <= n { return None; }
     let w: ArrayWString<N> = process.read_pointer_path64(a, &[STRING_CONTENTS_OFFSET]).ok()?;
-    if !(w.len() == min(n as usize, N)) { return None; }
+    if w.len() != min(n as usize, N) { return None; }
     String::from_utf16(&w.to_vec()).ok()
 }
 
@@ -2334,11 +2762,11 @@ fn read_string_object<const N: usize>(process: &Process, a: Address64) -> Option
 // --------------------------------------------------------
 
 pub fn is_menu(s: &str) -> bool {
-    s == MENU_TITLE || s == QUIT_TO_MENU || s == PERMA_DEATH
+    scene_table::is_menu_scene(s)
 }
 
 pub fn is_play_scene(s: &str) -> bool {
-    !NON_PLAY_SCENES.contains(&s) && !BAD_SCENE_NAMES.contains(&s)
+    scene_table::is_play_scene(s)
 }
 
 pub fn is_debug_save_state_scene(s: &str) -> bool {
@@ -2346,7 +2774,49 @@ pub fn is_debug_save_state_scene(s: &str) -> bool {
 }
 
 pub fn starts_with_any(full: &str, prefixes: &[&str]) -> bool {
-    prefixes.into_iter().any(|prefix| full.starts_with(prefix))
+    prefixes.iter().any(|prefix| full.starts_with(prefix))
 }
 
 // --------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn completion_items(bools: bool, counts: i32) -> CompletionItems {
+        CompletionItems {
+            charms_owned: if bools { 40 } else { 0 },
+            bosses: [bools; 13],
+            warrior_dreams: [bools; 7],
+            colosseum: [bools; 3],
+            dreamers: [bools; 3],
+            nail_arts: [bools; 3],
+            pantheons: [false; 4],
+            equipment: [bools; 6],
+            dream_nail: [bools; 2],
+            nail_smith_upgrades: 4 * counts,
+            charm_slots: 3 + 8 * counts,
+            max_health_base: 5 + 4 * counts,
+            mp_reserve_max: 99 * counts,
+            fireball_level: 2 * counts,
+            quake_level: 2 * counts,
+            scream_level: 2 * counts,
+        }
+    }
+
+    #[test]
+    fn completion_weighting() {
+        assert_eq!(completion_items(false, 0).percentage(), 0);
+        let mut everything = completion_items(true, 1);
+        assert_eq!(everything.percentage(), 108);
+        everything.pantheons = [true; 4];
+        assert_eq!(everything.percentage(), 112);
+        let mut equipment = completion_items(false, 0);
+        equipment.equipment[0] = true;
+        assert_eq!(equipment.percentage(), 2);
+        let mut spells = completion_items(false, 0);
+        spells.fireball_level = 3;
+        spells.mp_reserve_max = 32;
+        assert_eq!(spells.percentage(), 2);
+    }
+}
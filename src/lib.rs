@@ -3,15 +3,30 @@
 extern crate alloc;
 
 mod auto_splitter_settings;
+mod hksplitmaker;
 mod hollow_knight_memory;
+mod live_stats;
+mod lss;
+mod scene_table;
 mod settings_gui;
+mod settings_hash;
+mod settings_migration;
+mod split_catalogue;
+mod split_presets;
+mod split_profiles;
+mod split_times;
+mod split_validation;
 mod splits;
 
 use asr::{future::next_tick, Process};
 use asr::time::Duration;
 use asr::timer::TimerState;
 use settings_gui::SettingsGui;
+use split_times::{Comparisons, SplitTimes};
+use split_validation::SplitWarning;
+use splits::Split;
 use hollow_knight_memory::*;
+use live_stats::LiveStats;
 use ugly_widget::store::StoreGui;
 
 asr::async_main!(stable);
@@ -32,9 +47,16 @@ async fn main() {
 
     let mut ticks_since_gui = 0;
     let mut splits = gui.get_splits();
+    let mut split_options = gui.get_split_options();
     asr::print_message(&format!("splits: {:?}", splits));
+    let mut split_warnings = vec![];
+    check_splits(&gui, &splits, None, &mut split_warnings);
 
-    let mut auto_reset = splits::auto_reset_safe(&splits);
+    let mut auto_start = gui.get_auto_start();
+    let mut auto_reset = gui.get_auto_reset_split(&splits);
+    let mut live_stats_enabled = gui.get_live_stats();
+    let mut comparisons_enabled = gui.get_comparisons();
+    let mut split_times = SplitTimes::new(&splits);
 
     loop {
         let process = wait_attach_hollow_knight(&mut gui).await;
@@ -47,6 +69,8 @@ async fn main() {
                 next_tick().await;
                 let game_manager_finder = GameManagerFinder::wait_attach(&process).await;
                 let mut player_data_store = PlayerDataStore::new();
+                let mut live_stats = LiveStats::new();
+                let mut comparisons = Comparisons::new();
 
                 #[cfg(debug_assertions)]
                 asr::print_message(&format!("geo: {:?}", game_manager_finder.get_geo(&process)));
@@ -55,21 +79,44 @@ async fn main() {
                 if gui_splits != splits {
                     splits = gui_splits;
                     asr::print_message(&format!("splits: {:?}", splits));
-                    auto_reset = splits::auto_reset_safe(&splits);
+                    auto_reset = gui.get_auto_reset_split(&splits);
+                    split_times.set_splits(&splits);
                 }
+                let version = game_manager_finder.get_version_vec(&process);
+                check_splits(&gui, &splits, version.as_deref(), &mut split_warnings);
 
                 let mut i = 0;
                 let mut n = splits.len();
                 loop {
-                    let current_split = &splits[i];
                     let trans_now = scene_store.transition_now(&process, &game_manager_finder);
-                    if splits::splits(current_split, &process, &game_manager_finder, trans_now, &mut scene_store, &mut player_data_store) {
-                        split_index(&mut i, n);
-                        next_tick().await;
-                    } else if auto_reset && splits::splits(&splits[0], &process, &game_manager_finder, trans_now, &mut scene_store, &mut player_data_store) {
-                        i = 0;
-                        load_remover.load_removal(&process, &game_manager_finder, i);
-                        split_index(&mut i, n);
+                    // with no splits, such as after clearing the list, there's nothing to split or reset on
+                    if let Some(current_split) = splits.get(i) {
+                        let options = split_options.get(i).copied().unwrap_or_default();
+                        // the first split starts the timer and the last one ends it, so only the ones between are skipped
+                        let ready = options.enabled && (i == 0 || options.debounce <= split_times.since_last_split());
+                        if 0 < i && i + 1 < n && !options.enabled {
+                            skip_index(&mut i, &mut split_times);
+                        } else if (0 < i || auto_start) && ready && splits::splits(current_split, &process, &game_manager_finder, trans_now, &mut scene_store, &mut player_data_store) {
+                            split_index(&mut i, n, &mut split_times);
+                            next_tick().await;
+                        } else if let Some(reset_split) = auto_reset.as_ref().filter(|_| 0 < i && gui.auto_reset_allowed(i - 1)) {
+                            if splits::splits(reset_split, &process, &game_manager_finder, trans_now, &mut scene_store, &mut player_data_store) {
+                                i = 0;
+                                load_remover.load_removal(&process, &game_manager_finder, i);
+                                if auto_start && reset_split == &splits[0] {
+                                    split_index(&mut i, n, &mut split_times);
+                                } else {
+                                    asr::timer::reset();
+                                    split_times.reset();
+                                }
+                            }
+                        }
+                    }
+
+                    // detect manual starts
+                    if i == 0 && !auto_start && asr::timer::state() == TimerState::Running {
+                        i = 1;
+                        split_times.start();
                     }
 
                     if trans_now && scene_store.pair().old == MENU_TITLE {
@@ -79,19 +126,39 @@ async fn main() {
                     // detect manual resets
                     if 0 < i && asr::timer::state() == TimerState::NotRunning {
                         i = 0;
+                        split_times.reset();
                     }
 
                     load_remover.load_removal(&process, &game_manager_finder, i);
+                    split_times.tick(load_remover.game_time_paused);
+                    live_stats.update(&process, &game_manager_finder, &mut player_data_store, &live_stats_enabled);
+                    comparisons.update(&split_times, comparisons_enabled);
 
                     ticks_since_gui += 1;
                     if TICKS_PER_GUI <= ticks_since_gui && gui.load_update_store_if_unchanged() {
+                        if gui.switch_profile_if_changed() {
+                            split_times.reload(&gui.get_splits());
+                        }
+                        gui.import_splits_if_changed();
+                        gui.apply_split_preset();
                         let gui_splits = gui.get_splits();
                         if gui_splits != splits {
                             splits = gui_splits;
                             asr::print_message(&format!("splits: {:?}", splits));
-                            auto_reset = splits::auto_reset_safe(&splits);
                             n = splits.len();
+                            // keep the progress of a running timer, up to the last split,
+                            // but not back at the first split, which would start the timer again
+                            if 0 < i {
+                                i = i.min(n.saturating_sub(1)).max(1);
+                            }
+                            split_times.set_splits(&splits);
+                            check_splits(&gui, &splits, version.as_deref(), &mut split_warnings);
                         }
+                        split_options = gui.get_split_options();
+                        auto_start = gui.get_auto_start();
+                        auto_reset = gui.get_auto_reset_split(&splits);
+                        live_stats_enabled = gui.get_live_stats();
+                        comparisons_enabled = gui.get_comparisons();
                         ticks_since_gui = 0;
                     }
 
@@ -102,12 +169,26 @@ async fn main() {
     }
 }
 
-fn split_index(i: &mut usize, n: usize) {
+/// Validates the splits, printing the warnings when they change, and showing them as tooltips
+fn check_splits(gui: &SettingsGui, splits: &[Split], version: Option<&[i32]>, warnings: &mut Vec<SplitWarning>) {
+    let new_warnings = split_validation::validate_splits(splits, version);
+    if &new_warnings != warnings {
+        for w in new_warnings.iter() {
+            asr::print_message(&format!("WARNING: {}", w));
+        }
+        *warnings = new_warnings;
+    }
+    gui.show_split_warnings(warnings);
+}
+
+fn split_index(i: &mut usize, n: usize, split_times: &mut SplitTimes) {
     if *i == 0 {
         asr::timer::reset();
         asr::timer::start();
+        split_times.start();
     } else {
         asr::timer::split();
+        split_times.split(n - 1);
     }
     *i += 1;
     if n <= *i {
@@ -115,9 +196,17 @@ fn split_index(i: &mut usize, n: usize) {
     }
 }
 
+/// Skips a split between the first and the last
+fn skip_index(i: &mut usize, split_times: &mut SplitTimes) {
+    asr::timer::skip_split();
+    split_times.skip();
+    *i += 1;
+}
+
 struct LoadRemover {
     look_for_teleporting: bool,
     last_game_state: i32,
+    game_time_paused: bool,
     #[cfg(debug_assertions)]
     last_paused: bool,
 }
@@ -128,6 +217,7 @@ impl LoadRemover {
         LoadRemover { 
             look_for_teleporting: false,
             last_game_state: GAME_STATE_INACTIVE,
+            game_time_paused: true,
             #[cfg(debug_assertions)]
             last_paused: false,
         }
@@ -138,6 +228,7 @@ impl LoadRemover {
         // only remove loads if timer is running
         if asr::timer::state() != TimerState::Running {
             asr::timer::pause_game_time();
+            self.game_time_paused = true;
             return Some(());
         }
 
@@ -192,6 +283,7 @@ impl LoadRemover {
         } else {
             asr::timer::resume_game_time();
         }
+        self.game_time_paused = is_game_time_paused;
 
         self.last_game_state = game_state;
         #[cfg(debug_assertions)]
@@ -285,7 +377,7 @@ impl HitCounter {
 
         // TODO: make a togglable setting for whether dream falling counts as a hit or not
         if let Some(s) = maybe_scene_name {
-            if maybe_game_state == Some(GAME_STATE_ENTERING_LEVEL) && self.last_exiting_level.as_deref() == Some(&s) && s.starts_with("Dream_") {
+            if maybe_game_state == Some(GAME_STATE_ENTERING_LEVEL) && self.last_exiting_level.as_deref() == Some(&s) && scene_table::is_dream_scene(&s) {
                 self.hits += 1;
                 asr::timer::set_game_time(Duration::seconds(self.hits as i64));
                 asr::timer::set_variable_int("hits", self.hits);
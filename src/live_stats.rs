@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use asr::Process;
+
+use crate::hollow_knight_memory::{GameManagerFinder, PlayerDataStore};
+
+// --------------------------------------------------------
+
+/// Magic Power Reserve Max per soul vessel
+const MP_PER_VESSEL: i32 = 33;
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum LiveStat {
+    Geo,
+    Health,
+    Masks,
+    SoulVessels,
+    Essence,
+    Grubs,
+    CharmsOwned,
+    Completion,
+}
+
+impl LiveStat {
+    /// The name of the timer variable this stat is published as
+    pub fn variable_name(&self) -> &'static str {
+        match self {
+            LiveStat::Geo => "geo",
+            LiveStat::Health => "health",
+            LiveStat::Masks => "masks",
+            LiveStat::SoulVessels => "vessels",
+            LiveStat::Essence => "essence",
+            LiveStat::Grubs => "grubs",
+            LiveStat::CharmsOwned => "charms",
+            LiveStat::Completion => "completion",
+        }
+    }
+
+    fn read(&self, process: &Process, game_manager_finder: &GameManagerFinder, player_data_store: &mut PlayerDataStore) -> Option<i32> {
+        match self {
+            LiveStat::Geo => game_manager_finder.get_geo(process),
+            LiveStat::Health => game_manager_finder.get_health(process),
+            LiveStat::Masks => game_manager_finder.max_health_base(process),
+            LiveStat::SoulVessels => game_manager_finder.mp_reserve_max(process).map(|mp| mp / MP_PER_VESSEL),
+            LiveStat::Essence => game_manager_finder.dream_orbs(process),
+            LiveStat::Grubs => game_manager_finder.grubs_collected(process),
+            LiveStat::CharmsOwned => game_manager_finder.charms_owned(process),
+            LiveStat::Completion => player_data_store.completion_percentage(process, game_manager_finder),
+        }
+    }
+}
+
+// --------------------------------------------------------
+
+/// Publishes the enabled stats as timer variables,
+/// only setting a variable when its value changes.
+pub struct LiveStats {
+    published: BTreeMap<LiveStat, i32>,
+}
+
+impl LiveStats {
+    pub fn new() -> LiveStats {
+        LiveStats { published: BTreeMap::new() }
+    }
+
+    pub fn update(&mut self, process: &Process, game_manager_finder: &GameManagerFinder, player_data_store: &mut PlayerDataStore, enabled: &[LiveStat]) {
+        // forget disabled stats so they get published again if re-enabled
+        self.published.retain(|stat, _| enabled.contains(stat));
+        // PlayerData is not meaningful on the menu
+        if !game_manager_finder.is_game_state_non_menu(process) { return; }
+        for stat in enabled {
+            let Some(value) = stat.read(process, game_manager_finder, player_data_store) else { continue; };
+            if self.published.get(stat) == Some(&value) { continue; }
+            asr::timer::set_variable_int(stat.variable_name(), value);
+            self.published.insert(*stat, value);
+        }
+    }
+}
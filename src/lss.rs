@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+use xmltree::{Element, XMLNode};
+
+use crate::auto_splitter_settings::XMLSettings;
+use crate::settings_gui::{element_to_string, splits_from_settings, splits_to_settings_element, text_element};
+use crate::splits::{self, Split};
+
+/// The parts of a LiveSplit `.lss` splits file that the autosplitter uses
+#[derive(Clone, Debug)]
+pub struct LssSplits {
+    /// The names of the segments, in order
+    pub segment_names: Vec<String>,
+    /// The autosplits from the AutoSplitterSettings, starting with the one that starts the timer
+    pub splits: Vec<Split>,
+}
+
+impl LssSplits {
+    /// Parses a complete `.lss` file, or returns None if it isn't one
+    pub fn from_lss_string(s: &str) -> Option<LssSplits> {
+        let run = Element::parse(s.as_bytes()).ok()?;
+        if run.name != "Run" {
+            return None;
+        }
+        let segment_names = run.get_child("Segments").map(|segments| {
+            segments.children.iter().filter_map(|c| {
+                let segment = c.as_element().filter(|e| e.name == "Segment")?;
+                Some(segment.get_child("Name").and_then(|n| n.get_text()).unwrap_or_default().to_string())
+            }).collect()
+        }).unwrap_or_default();
+        let settings = run.get_child("AutoSplitterSettings").map(|e| {
+            XMLSettings::from_xml_element(e, &[("Splits", "Split")])
+        }).unwrap_or_default();
+        let splits = splits_from_settings(&settings);
+        Some(LssSplits { segment_names, splits })
+    }
+
+    /// The splits with suggested segment names from `splits::default_segment_names`
+    pub fn from_splits(splits: Vec<Split>) -> LssSplits {
+        LssSplits { segment_names: splits::default_segment_names(&splits), splits }
+    }
+
+    /// A skeleton `.lss` file with the segment names and the AutoSplitterSettings,
+    /// but no times or attempt history
+    pub fn to_lss_string(&self, category_name: &str) -> String {
+        let mut run = Element::new("Run");
+        run.attributes.insert("version".to_string(), "1.7.0".to_string());
+        run.children.push(XMLNode::Element(Element::new("GameIcon")));
+        run.children.push(XMLNode::Element(text_element("GameName", "Hollow Knight")));
+        run.children.push(XMLNode::Element(text_element("CategoryName", category_name)));
+        run.children.push(XMLNode::Element(text_element("Offset", "00:00:00")));
+        run.children.push(XMLNode::Element(text_element("AttemptCount", "0")));
+        run.children.push(XMLNode::Element(Element::new("AttemptHistory")));
+        let mut segments = Element::new("Segments");
+        for name in self.segment_names.iter() {
+            let mut segment = Element::new("Segment");
+            segment.children.push(XMLNode::Element(text_element("Name", name)));
+            segment.children.push(XMLNode::Element(Element::new("Icon")));
+            let mut split_times = Element::new("SplitTimes");
+            let mut personal_best = Element::new("SplitTime");
+            personal_best.attributes.insert("name".to_string(), "Personal Best".to_string());
+            split_times.children.push(XMLNode::Element(personal_best));
+            segment.children.push(XMLNode::Element(split_times));
+            segment.children.push(XMLNode::Element(Element::new("BestSegmentTime")));
+            segment.children.push(XMLNode::Element(Element::new("SegmentHistory")));
+            segments.children.push(XMLNode::Element(segment));
+        }
+        run.children.push(XMLNode::Element(segments));
+        run.children.push(XMLNode::Element(splits_to_settings_element(&self.splits, false)));
+        element_to_string(&run)
+    }
+
+    /// Whether there is one autosplit to start the timer, plus one for the end of each segment
+    pub fn is_valid(&self) -> bool {
+        self.splits.len() == self.segment_names.len() + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lss_files() {
+        for lss in [include_str!("../splits-direct.lss"), include_str!("../splits-control.lss")] {
+            let lss_splits = LssSplits::from_lss_string(lss).expect("splits file should parse");
+            assert!(lss_splits.is_valid(), "{} splits for {} segments", lss_splits.splits.len(), lss_splits.segment_names.len());
+        }
+        // the autosplits for splits-lswasr.lss are in its layout instead
+        let lswasr = LssSplits::from_lss_string(include_str!("../splits-lswasr.lss")).expect("splits file should parse");
+        assert_eq!(lswasr.segment_names.len(), 13);
+        assert!(LssSplits::from_lss_string("<Layout></Layout>").is_none());
+        assert!(LssSplits::from_lss_string("not xml").is_none());
+    }
+
+    #[test]
+    fn lss_round_trip() {
+        let lss_splits = LssSplits::from_lss_string(include_str!("../splits-direct.lss")).expect("splits file should parse");
+        let again = LssSplits::from_lss_string(&lss_splits.to_lss_string("Any%")).expect("exported splits file should parse");
+        assert_eq!(again.segment_names, lss_splits.segment_names);
+        assert_eq!(again.splits, lss_splits.splits);
+        let from_splits = LssSplits::from_splits(vec![Split::StartNewGame, Split::Hornet1, Split::Grub1, Split::Grub2, Split::EndingSplit]);
+        let again = LssSplits::from_lss_string(&from_splits.to_lss_string("Hornet")).expect("exported splits file should parse");
+        assert_eq!(again.segment_names, from_splits.segment_names);
+        assert_eq!(again.splits, from_splits.splits);
+    }
+}
@@ -0,0 +1,677 @@
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use asr::settings::Gui;
+use asr::settings::gui::{add_choice, add_choice_option, set_tooltip, Widget};
+use asr::watcher::Pair;
+use serde::{Deserialize, Serialize};
+use ugly_widget::radio_button::{RadioButtonOptions, options_description, options_str, options_value};
+use ugly_widget::store::StoreWidget;
+
+use crate::hollow_knight_memory::*;
+
+// --------------------------------------------------------
+
+const NON_PLAY_SCENES: [&str; 16] = [
+    PRE_MENU_INTRO,
+    MENU_TITLE,
+    QUIT_TO_MENU,
+    INTRO_CUTSCENE,
+    OPENING_SEQUENCE,
+    GG_ENTRANCE_CUTSCENE,
+    "Cinematic_Ending_A",
+    "Cinematic_Ending_B",
+    "Cinematic_Ending_C",
+    "Cinematic_Ending_D",
+    "Cinematic_Ending_E",
+    "End_Credits",
+    "Cinematic_MrMushroom",
+    "End_Game_Completion",
+    PERMA_DEATH,
+    "PermaDeath_Unlock",
+];
+
+const FUNGAL_WASTES_ENTRY_SCENES: &[&str] = &[
+    "Fungus2_06", // Room outside Leg Eater
+    "Fungus2_03", // From Queens' Station
+    "Fungus2_23", // Bretta from Waterways
+    "Fungus2_20", // Spore Shroom room, from QG (this one's unlikely to come up)
+];
+
+const CRYSTAL_PEAK_ENTRY_SCENES: &[&str] = &[
+    "Mines_02",
+    "Mines_10",
+];
+
+const WATERWAYS_ENTRY_SCENES: &[&str] = &[
+    "Waterways_01", // Simple Key manhole entrance
+    // Note: Waterways_06 does not show the Area Text
+    "Waterways_07", // Where the Spike-tunnel and KE-Tram-CDash entrances meet
+];
+
+const FOG_CANYON_ENTRY_SCENES: &[&str] = &[
+    "Fungus3_01", // West Fog Canyon entrance from Greenpath
+    "Fungus3_02", // West Fog Canyon entrance from Queen's Station or QGA
+    "Fungus3_24", // West Fog Canyon entrance from Queen's Gardens via Overgrown Mound
+    "Fungus3_26", // East Fog Canyon, where the Crossroads acid and Leg Eater acid entrances meet
+];
+
+const QUEENS_GARDENS_ENTRY_SCENES: &[&str] = &[
+    "Fungus3_34",
+    "Deepnest_43",
+];
+
+const DEEPNEST_ENTRY_SCENES: &[&str] = &[
+    "Fungus2_25", // Room after Mantis Lords
+    "Deepnest_42", // Room outside Mask Maker
+    "Abyss_03b", // Deepnest Tram
+    "Deepnest_01b", // Near Spore Shroom
+];
+
+/// Scene name prefixes and their areas, as the prefixes that a ScenePattern can choose
+const AREA_PREFIXES: &[(&str, Area)] = &[
+    ("Pre_Menu_Intro", Area::Menu),
+    ("Menu_Title", Area::Menu),
+    ("Quit_To_Menu", Area::Menu),
+    ("PermaDeath", Area::Menu),
+    ("Cinematic_", Area::Cutscene),
+    ("Cutscene_Boss_Door", Area::BlackEggTemple),
+    ("End_", Area::Cutscene),
+    ("Intro_Cutscene", Area::Cutscene),
+    ("Opening_Sequence", Area::Cutscene),
+    ("Tutorial_01", Area::KingsPass),
+    ("Town", Area::Dirtmouth),
+    ("Room_Town_Stag_Station", Area::Dirtmouth),
+    ("Room_mapper", Area::Dirtmouth),
+    ("Room_shop", Area::Dirtmouth),
+    ("Room_Ouiji", Area::Dirtmouth),
+    ("Room_Sly_Storeroom", Area::Dirtmouth),
+    ("Room_Bretta", Area::Dirtmouth),
+    ("Room_Jinn", Area::Dirtmouth),
+    ("Grimm_", Area::Dirtmouth),
+    ("Crossroads_", Area::ForgottenCrossroads),
+    ("Room_Charm_Shop", Area::ForgottenCrossroads),
+    ("Room_temple", Area::BlackEggTemple),
+    ("Room_Final_Boss", Area::BlackEggTemple),
+    ("Room_ruinhouse", Area::ForgottenCrossroads),
+    ("Room_Mender_House", Area::ForgottenCrossroads),
+    ("Fungus1_", Area::Greenpath),
+    ("Room_nailmaster_02", Area::Greenpath),
+    ("Room_Slug_Shrine", Area::Greenpath),
+    ("Fungus2_", Area::FungalWastes),
+    ("Room_Fungus_Shaman", Area::FungalWastes),
+    ("Fungus3_archive", Area::FogCanyon),
+    ("Fungus3_", Area::QueensGardens),
+    ("Room_Queen", Area::QueensGardens),
+    ("Ruins", Area::CityOfTears),
+    ("Room_nailsmith", Area::CityOfTears),
+    ("Waterways_", Area::RoyalWaterways),
+    ("Mines_", Area::CrystalPeak),
+    ("Cliffs_", Area::HowlingCliffs),
+    ("Room_nailmaster_03", Area::KingdomsEdge),
+    ("Room_nailmaster", Area::HowlingCliffs),
+    ("RestingGrounds_", Area::RestingGrounds),
+    ("Room_Mansion", Area::RestingGrounds),
+    ("Room_Tram_RG", Area::RestingGrounds),
+    ("Deepnest_East_", Area::KingdomsEdge),
+    ("Room_Wyrm", Area::KingdomsEdge),
+    ("Room_Colosseum_", Area::Colosseum),
+    ("Hive_", Area::Hive),
+    ("Deepnest_", Area::Deepnest),
+    ("Room_spider_small", Area::Deepnest),
+    ("Abyss_", Area::AncientBasin),
+    ("White_Palace_", Area::WhitePalace),
+    ("Dream_", Area::Dream),
+    ("GG_", Area::Godhome),
+];
+
+/// Boss arenas outside of Godhome
+const BOSS_ARENA_SCENES: &[&str] = &[
+    "Crossroads_04", // Gruz Mother
+    "Crossroads_09", // Brooding Mawlek
+    "Crossroads_10", // False Knight
+    "Crossroads_10_boss",
+    "Fungus1_04", // Hornet Protector
+    "Fungus1_29", // Massive Moss Charger
+    "Fungus2_15", // Mantis Lords
+    "Fungus3_23", // Traitor Lord
+    "Fungus3_archive_02", // Uumuu
+    "Fungus3_archive_02_boss",
+    "Ruins1_24", // Soul Master
+    "Ruins2_03", // Watcher Knights
+    "Ruins2_03_boss",
+    "Ruins2_11", // The Collector
+    "Waterways_05", // Dung Defender
+    "Waterways_12", // Flukemarm
+    "Mines_18", // Crystal Guardian
+    "Mines_32", // Enraged Guardian
+    "Deepnest_32", // Nosk
+    "Deepnest_East_Hornet", // Hornet Sentinel
+    "Hive_05", // Hive Knight
+    "Abyss_19", // Broken Vessel
+    "Grimm_Main_Tent", // Troupe Master Grimm
+    "Grimm_Nightmare", // Nightmare King Grimm
+    "Dream_01_False_Knight", // Failed Champion
+    "Dream_02_Mage_Lord", // Soul Tyrant
+    "Dream_03_Infected_Knight", // Lost Kin
+    "Dream_Mighty_Zote", // Grey Prince Zote
+    "Room_Final_Boss_Core", // The Hollow Knight
+    "Dream_Final_Boss", // The Radiance
+];
+
+/// Godhome scenes that are not boss arenas
+const GODHOME_NON_ARENA_SCENES: &[&str] = &[
+    "GG_Atrium",
+    "GG_Atrium_Roof",
+    "GG_Blue_Room",
+    "GG_Boss_Door_Entrance",
+    "GG_Door_5_Finale",
+    "GG_End_Sequence",
+    "GG_Engine",
+    "GG_Engine_Prime",
+    "GG_Engine_Root",
+    "GG_Entrance_Cutscene",
+    "GG_Land_of_Storms",
+    "GG_Pipeway",
+    "GG_Spa",
+    "GG_Unlock_Wastes",
+    "GG_Unn",
+    "GG_Waterways",
+    "GG_Workshop",
+    "GG_Wyrm",
+];
+
+// --------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Area {
+    Menu,
+    Cutscene,
+    KingsPass,
+    Dirtmouth,
+    ForgottenCrossroads,
+    BlackEggTemple,
+    Greenpath,
+    FungalWastes,
+    FogCanyon,
+    QueensGardens,
+    CityOfTears,
+    RoyalWaterways,
+    CrystalPeak,
+    HowlingCliffs,
+    RestingGrounds,
+    Deepnest,
+    KingdomsEdge,
+    Colosseum,
+    Hive,
+    AncientBasin,
+    TheAbyss,
+    WhitePalace,
+    Dream,
+    Godhome,
+    Unknown,
+}
+
+impl Area {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Area::Menu => "Menu",
+            Area::Cutscene => "Cutscene",
+            Area::KingsPass => "King's Pass",
+            Area::Dirtmouth => "Dirtmouth",
+            Area::ForgottenCrossroads => "Forgotten Crossroads",
+            Area::BlackEggTemple => "Black Egg Temple",
+            Area::Greenpath => "Greenpath",
+            Area::FungalWastes => "Fungal Wastes",
+            Area::FogCanyon => "Fog Canyon",
+            Area::QueensGardens => "Queen's Gardens",
+            Area::CityOfTears => "City of Tears",
+            Area::RoyalWaterways => "Royal Waterways",
+            Area::CrystalPeak => "Crystal Peak",
+            Area::HowlingCliffs => "Howling Cliffs",
+            Area::RestingGrounds => "Resting Grounds",
+            Area::Deepnest => "Deepnest",
+            Area::KingdomsEdge => "Kingdom's Edge",
+            Area::Colosseum => "Colosseum of Fools",
+            Area::Hive => "Hive",
+            Area::AncientBasin => "Ancient Basin",
+            Area::TheAbyss => "The Abyss",
+            Area::WhitePalace => "White Palace",
+            Area::Dream => "Dream",
+            Area::Godhome => "Godhome",
+            Area::Unknown => "Unknown",
+        }
+    }
+
+    /// The scenes that show the Area Text when entering this area,
+    /// or an empty slice for areas that don't have entry splits
+    pub fn entry_scenes(&self) -> &'static [&'static str] {
+        match self {
+            Area::FungalWastes => FUNGAL_WASTES_ENTRY_SCENES,
+            Area::CrystalPeak => CRYSTAL_PEAK_ENTRY_SCENES,
+            Area::RoyalWaterways => WATERWAYS_ENTRY_SCENES,
+            Area::FogCanyon => FOG_CANYON_ENTRY_SCENES,
+            Area::QueensGardens => QUEENS_GARDENS_ENTRY_SCENES,
+            Area::Deepnest => DEEPNEST_ENTRY_SCENES,
+            _ => &[],
+        }
+    }
+
+    /// Whether the transition enters one of the entry scenes of this area
+    pub fn is_entry_transition(&self, p: &Pair<&str>) -> bool {
+        starts_with_any(p.current, self.entry_scenes()) && p.current != p.old
+    }
+}
+
+// --------------------------------------------------------
+
+/// The MapZone enum from the game, in the same order so that
+/// values read from memory can be converted with from_i32
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions, Serialize, StoreWidget)]
+pub enum MapZone {
+    /// None
+    #[default]
+    None,
+    /// Test Area
+    TestArea,
+    /// King's Pass
+    KingsPass,
+    /// Howling Cliffs
+    Cliffs,
+    /// Dirtmouth
+    Town,
+    /// Forgotten Crossroads
+    Crossroads,
+    /// Greenpath
+    GreenPath,
+    /// Queen's Gardens
+    RoyalGardens,
+    /// Fog Canyon
+    FogCanyon,
+    /// Fungal Wastes
+    Wastes,
+    /// Deepnest
+    Deepnest,
+    /// Hive
+    Hive,
+    /// Kingdom's Edge
+    BoneForest,
+    /// Palace Grounds
+    PalaceGrounds,
+    /// Crystal Peak
+    Mines,
+    /// Resting Grounds
+    RestingGrounds,
+    /// City of Tears
+    City,
+    /// Dream World
+    DreamWorld,
+    /// Colosseum of Fools
+    Colosseum,
+    /// The Abyss
+    Abyss,
+    /// Royal Quarter
+    RoyalQuarter,
+    /// White Palace
+    WhitePalace,
+    /// Ancestral Mound
+    ShamanTemple,
+    /// Royal Waterways
+    Waterways,
+    /// Queen's Station
+    QueensStation,
+    /// Kingdom's Edge Outskirts
+    Outskirts,
+    /// King's Station
+    KingsStation,
+    /// Soul Sanctum Tower
+    MageTower,
+    /// Upper Tram
+    TramUpper,
+    /// Lower Tram
+    TramLower,
+    /// Black Egg Temple
+    FinalBoss,
+    /// Soul Sanctum
+    SoulSociety,
+    /// Lake of Unn
+    AcidLake,
+    /// Stone Sanctuary
+    NoEyesTemple,
+    /// Cast-Off Shell
+    Wyrmskin,
+    /// Grey Mourner's House
+    GreyMourner,
+    /// Mantis Village
+    MantisVillage,
+    /// Ruined Tramway
+    RuinedTramway,
+    /// Distant Village
+    DistantVillage,
+    /// Abyss Depths
+    AbyssDeep,
+    /// Isma's Grove
+    IsmasGrove,
+    /// Weavers' Den
+    WeaverDen,
+    /// Watcher's Spire
+    LuriensTower,
+    /// Tower of Love
+    LoveTower,
+    /// Spirits' Glade
+    Glade,
+    /// Blue Lake
+    BlueLake,
+    /// Crystallised Mound Peak
+    Peak,
+    /// Joni's Repose
+    JoniGrave,
+    /// Overgrown Mound
+    OvergrownMound,
+    /// Crystallised Mound
+    CrystalMound,
+    /// Beast's Den
+    BeastsDen,
+    /// Godhome
+    GodsGlory,
+    /// Junk Pit
+    GodseekerWaste,
+}
+
+impl MapZone {
+    pub fn from_i32(i: i32) -> Option<MapZone> {
+        match i {
+            0 => Some(MapZone::None),
+            1 => Some(MapZone::TestArea),
+            2 => Some(MapZone::KingsPass),
+            3 => Some(MapZone::Cliffs),
+            4 => Some(MapZone::Town),
+            5 => Some(MapZone::Crossroads),
+            6 => Some(MapZone::GreenPath),
+            7 => Some(MapZone::RoyalGardens),
+            8 => Some(MapZone::FogCanyon),
+            9 => Some(MapZone::Wastes),
+            10 => Some(MapZone::Deepnest),
+            11 => Some(MapZone::Hive),
+            12 => Some(MapZone::BoneForest),
+            13 => Some(MapZone::PalaceGrounds),
+            14 => Some(MapZone::Mines),
+            15 => Some(MapZone::RestingGrounds),
+            16 => Some(MapZone::City),
+            17 => Some(MapZone::DreamWorld),
+            18 => Some(MapZone::Colosseum),
+            19 => Some(MapZone::Abyss),
+            20 => Some(MapZone::RoyalQuarter),
+            21 => Some(MapZone::WhitePalace),
+            22 => Some(MapZone::ShamanTemple),
+            23 => Some(MapZone::Waterways),
+            24 => Some(MapZone::QueensStation),
+            25 => Some(MapZone::Outskirts),
+            26 => Some(MapZone::KingsStation),
+            27 => Some(MapZone::MageTower),
+            28 => Some(MapZone::TramUpper),
+            29 => Some(MapZone::TramLower),
+            30 => Some(MapZone::FinalBoss),
+            31 => Some(MapZone::SoulSociety),
+            32 => Some(MapZone::AcidLake),
+            33 => Some(MapZone::NoEyesTemple),
+            34 => Some(MapZone::Wyrmskin),
+            35 => Some(MapZone::GreyMourner),
+            36 => Some(MapZone::MantisVillage),
+            37 => Some(MapZone::RuinedTramway),
+            38 => Some(MapZone::DistantVillage),
+            39 => Some(MapZone::AbyssDeep),
+            40 => Some(MapZone::IsmasGrove),
+            41 => Some(MapZone::WeaverDen),
+            42 => Some(MapZone::LuriensTower),
+            43 => Some(MapZone::LoveTower),
+            44 => Some(MapZone::Glade),
+            45 => Some(MapZone::BlueLake),
+            46 => Some(MapZone::Peak),
+            47 => Some(MapZone::JoniGrave),
+            48 => Some(MapZone::OvergrownMound),
+            49 => Some(MapZone::CrystalMound),
+            50 => Some(MapZone::BeastsDen),
+            51 => Some(MapZone::GodsGlory),
+            52 => Some(MapZone::GodseekerWaste),
+            _ => None,
+        }
+    }
+}
+
+// --------------------------------------------------------
+
+#[derive(Clone, Debug)]
+pub struct SceneInfo {
+    pub name: String,
+    /// The index in the build settings
+    pub build_index: u32,
+    pub area: Area,
+    /// The map zone, where scene_table.json has one for the scene
+    pub map_zone: Option<MapZone>,
+    pub is_dream: bool,
+    pub is_boss_arena: bool,
+    pub is_menu: bool,
+    pub is_play: bool,
+}
+
+impl SceneInfo {
+    fn new(build_index: u32, e: SceneTableEntry) -> SceneInfo {
+        SceneInfo {
+            is_dream: is_dream_scene_name(&e.name),
+            is_boss_arena: is_boss_arena_scene_name(&e.name),
+            is_menu: is_menu_scene_name(&e.name),
+            is_play: is_play_scene_name(&e.name),
+            name: e.name,
+            build_index,
+            area: e.area,
+            map_zone: e.map_zone,
+        }
+    }
+
+    /// The scene with its area or map zone, for choosing it from the scenes in scene_table.json
+    fn description(&self) -> String {
+        let place = match self.map_zone {
+            Some(z) => options_description(&z),
+            None => self.area.description(),
+        };
+        if self.is_boss_arena {
+            format!("{} - {}, boss arena", self.name, place)
+        } else {
+            format!("{} - {}", self.name, place)
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SceneTableEntry {
+    name: String,
+    area: Area,
+    #[serde(default)]
+    map_zone: Option<MapZone>,
+}
+
+struct SceneTable {
+    /// Scenes in build index order
+    scenes: Vec<SceneInfo>,
+    /// Scene name to index in scenes
+    by_name: BTreeMap<String, usize>,
+}
+
+fn scene_table() -> &'static SceneTable {
+    static SCENE_TABLE: OnceLock<SceneTable> = OnceLock::new();
+    SCENE_TABLE.get_or_init(|| {
+        let entries: BTreeMap<String, SceneTableEntry> = serde_json::from_str(include_str!("scene_table.json")).expect("scene_table.json should be a map from build indices to scenes");
+        let mut scenes: Vec<SceneInfo> = entries.into_iter().map(|(i, e)| {
+            SceneInfo::new(i.parse().expect("scene_table.json keys should be build indices"), e)
+        }).collect();
+        scenes.sort_by_key(|s| s.build_index);
+        let by_name = scenes.iter().enumerate().map(|(i, s)| (s.name.clone(), i)).collect();
+        SceneTable { scenes, by_name }
+    })
+}
+
+/// The scenes from scene_table.json, in build index order
+pub fn scenes() -> &'static [SceneInfo] {
+    &scene_table().scenes
+}
+
+/// Looks up a scene in scene_table.json by name
+pub fn scene_info(name: &str) -> Option<&'static SceneInfo> {
+    let table = scene_table();
+    table.by_name.get(name).map(|&i| &table.scenes[i])
+}
+
+/// Whether the scene is a dream, from scene_table.json, or from its name for scenes missing from it
+pub fn is_dream_scene(name: &str) -> bool {
+    scene_info(name).map_or_else(|| is_dream_scene_name(name), |s| s.is_dream)
+}
+
+pub fn is_menu_scene(name: &str) -> bool {
+    scene_info(name).map_or_else(|| is_menu_scene_name(name), |s| s.is_menu)
+}
+
+pub fn is_play_scene(name: &str) -> bool {
+    scene_info(name).map_or_else(|| is_play_scene_name(name), |s| s.is_play)
+}
+
+fn is_dream_scene_name(name: &str) -> bool {
+    name.starts_with("Dream_")
+}
+
+fn is_boss_arena_scene_name(name: &str) -> bool {
+    BOSS_ARENA_SCENES.contains(&name) || (name.starts_with("GG_") && !GODHOME_NON_ARENA_SCENES.contains(&name))
+}
+
+fn is_menu_scene_name(name: &str) -> bool {
+    name == MENU_TITLE || name == QUIT_TO_MENU || name == PERMA_DEATH
+}
+
+fn is_play_scene_name(name: &str) -> bool {
+    !NON_PLAY_SCENES.contains(&name) && !BAD_SCENE_NAMES.contains(&name)
+}
+
+// --------------------------------------------------------
+
+/// A pattern that scene names can match, for splits on arbitrary transitions
+#[derive(Clone, Debug, Default, Deserialize, Eq, Ord, PartialEq, PartialOrd, RadioButtonOptions, Serialize)]
+pub enum ScenePattern {
+    /// Any scene
+    #[default]
+    Any,
+    /// Exactly the scene
+    Exact(String),
+    /// Any scene starting with
+    Prefix(String),
+}
+
+impl ScenePattern {
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            ScenePattern::Any => true,
+            ScenePattern::Exact(s) => name == s,
+            ScenePattern::Prefix(s) => name.starts_with(s.as_str()),
+        }
+    }
+
+    fn scene_mut(&mut self) -> Option<&mut String> {
+        match self {
+            ScenePattern::Any => None,
+            ScenePattern::Exact(s) | ScenePattern::Prefix(s) => Some(s),
+        }
+    }
+}
+
+/// The choices for scenes in a ScenePattern: every scene in
+/// scene_table.json, followed by the area prefixes that aren't scenes
+fn scene_choices() -> Vec<(&'static str, String)> {
+    let mut choices: Vec<(&'static str, String)> = scenes().iter().map(|s| (s.name.as_str(), s.description())).collect();
+    for (prefix, area) in AREA_PREFIXES {
+        if !choices.iter().any(|(s, _)| s == prefix) {
+            choices.push((prefix, format!("{} - {}", prefix, area.description())));
+        }
+    }
+    choices
+}
+
+impl Widget for ScenePattern {
+    type Args = ();
+
+    fn register(key: &str, description: &str, _args: Self::Args) -> Self {
+        add_choice(key, description, options_str(&ScenePattern::default()));
+        for o in ScenePattern::radio_button_options() {
+            add_choice_option(key, o.key, o.description);
+        }
+        let key_scene = format!("{}_scene", key);
+        add_choice(&key_scene, "Scene", "");
+        add_choice_option(&key_scene, "", "None");
+        for (s, description) in scene_choices() {
+            add_choice_option(&key_scene, s, &description);
+        }
+        set_tooltip(&key_scene, "Not used when matching any scene");
+        let mut v = ScenePattern::default();
+        v.update_from(&asr::settings::Map::load(), key, ());
+        v
+    }
+
+    fn update_from(&mut self, settings_map: &asr::settings::Map, key: &str, _args: Self::Args) {
+        let Some(mut pattern) = settings_map.get(key).and_then(|v| v.get_string()).and_then(|s| options_value::<ScenePattern>(&s)) else { return; };
+        if let Some(s) = pattern.scene_mut() {
+            *s = settings_map.get(&format!("{}_scene", key)).and_then(|v| v.get_string()).unwrap_or_default();
+        }
+        *self = pattern;
+    }
+}
+
+impl StoreWidget for ScenePattern {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        let a = if settings_map.get(key).is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s)) {
+            false
+        } else {
+            settings_map.insert(key, new_s);
+            true
+        };
+        let b = match self {
+            ScenePattern::Any => false,
+            ScenePattern::Exact(s) | ScenePattern::Prefix(s) => {
+                let key_scene = format!("{}_scene", key);
+                if settings_map.get(&key_scene).is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| &old_s == s)) {
+                    false
+                } else {
+                    settings_map.insert(&key_scene, s.as_str());
+                    true
+                }
+            }
+        };
+        a || b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scene_table_json() {
+        let scenes = scenes();
+        assert!(!scenes.is_empty());
+        assert_eq!(scene_table().by_name.len(), scenes.len(), "scene names should be unique");
+        for s in scenes {
+            assert_ne!(s.area, Area::Unknown, "{} should have an area", s.name);
+        }
+        assert_eq!(scene_info("Town").map(|s| (s.area, s.map_zone)), Some((Area::Dirtmouth, Some(MapZone::Town))));
+    }
+
+    #[test]
+    fn scene_flags() {
+        assert!(is_menu_scene(MENU_TITLE));
+        assert!(!is_play_scene(MENU_TITLE));
+        assert!(is_play_scene("Town"));
+        assert!(is_dream_scene("Dream_Guardian_Lurien"));
+        // scenes missing from scene_table.json
+        assert!(is_dream_scene("Dream_Backer_Shrine"));
+        assert!(is_play_scene("Fungus3_44"));
+        assert!(!is_play_scene(PERMA_DEATH));
+    }
+}
@@ -1,35 +1,256 @@
-use asr::settings::gui::{Gui, Title};
+use std::cell::RefCell;
+use std::str::FromStr;
 
-use ugly_widget::{ugly_list::UglyList, store::{StoreWidget, StoreGui}};
+use asr::settings::gui::{set_tooltip, Gui, Title, Widget};
+use asr::timer::TimerState;
+use xmltree::{Element, EmitterConfig, XMLNode};
 
-use crate::{splits::{Split, self}, auto_splitter_settings::{Settings, SettingsObject, XMLSettings}};
+use ugly_widget::{args::SetHeadingLevel, history::History, radio_button::{options_description, options_str, options_value, RadioButton, RadioButtonOptions}, ugly_list::{UglyList, UglyListArgs}, ugly_number::UglyNumber, ugly_text::{UglyText, UglyTextArgs}, store::{StoreWidget, StoreGui}};
 
-#[derive(Gui)]
+use crate::{splits::{Split, SplitEntry, SplitOptions, self}, auto_splitter_settings::{Settings, SettingsObject, XMLSettings}, live_stats::LiveStat, lss::LssSplits, scene_table::ScenePattern, hksplitmaker::HKSplitMakerCategory, settings_hash::stable_hash, settings_migration, split_presets::SplitPreset, split_profiles::{Profile, ProfileButtons}, split_validation::SplitWarning};
+
+#[derive(Gui, StoreGui)]
 pub struct SettingsGui {
     /// General Settings
     _general_settings: Title,
+    /// Auto Start
+    /// 
+    /// Starts the timer when the first split splits.
+    /// When this is off, start the timer manually, and the first split is skipped.
+    #[default = true]
+    auto_start: bool,
+    /// Auto Reset
+    /// 
+    /// What resets the timer automatically
+    auto_reset: AutoReset,
+    /// Auto Reset Scene
+    /// 
+    /// The scene that resets the timer when Auto Reset is Scene
+    auto_reset_scene: ScenePattern,
+    /// Auto Reset Before Split
+    /// 
+    /// Only resets automatically when fewer than this many splits are done, or always when 0
+    #[heading_level = 1]
+    auto_reset_before_split: UglyNumber,
+    /// Live Stats
+    /// 
+    /// Values published as timer variables, for display with a Variable component
+    #[heading_level = 1]
+    _live_stats: Title,
+    /// Geo
+    /// 
+    /// Publishes the timer variable "geo"
+    live_stat_geo: bool,
+    /// Health
+    /// 
+    /// Publishes the timer variable "health"
+    live_stat_health: bool,
+    /// Masks
+    /// 
+    /// Publishes the timer variable "masks"
+    live_stat_masks: bool,
+    /// Soul Vessels
+    /// 
+    /// Publishes the timer variable "vessels"
+    live_stat_soul_vessels: bool,
+    /// Essence
+    /// 
+    /// Publishes the timer variable "essence"
+    live_stat_essence: bool,
+    /// Grubs
+    /// 
+    /// Publishes the timer variable "grubs"
+    live_stat_grubs: bool,
+    /// Charms Owned
+    /// 
+    /// Publishes the timer variable "charms"
+    live_stat_charms_owned: bool,
+    /// Completion %
+    /// 
+    /// Publishes the timer variable "completion"
+    live_stat_completion: bool,
+    /// Comparisons
+    /// 
+    /// Publishes the timer variables "delta", the game time of the last split compared to the personal best,
+    /// and "possible_time_save", the personal best's current segment compared to the best segment
+    live_stat_comparisons: bool,
+    /// Profile
+    /// 
+    /// Which named split list the Splits below belong to, each with its own segment names and split times.
+    /// Choosing another profile keeps the Splits in the old one and loads the new one's,
+    /// or starts the new one as a copy of the Splits if it's empty.
+    /// Only switches while the timer isn't running, so a run keeps the splits it started with.
+    /// Names are set outside of this GUI in split_profile_1_name and so on, or from a Split Preset,
+    /// and are shown here after the autosplitter restarts.
+    #[heading_level = 1]
+    profile: ProfileButtons,
+    /// Split Preset
+    /// 
+    /// Replaces the Splits below with a built-in list for a common category,
+    /// then goes back to None
+    #[heading_level = 1]
+    split_preset: RadioButton<SplitPreset>,
+    /// Import Splits
+    /// 
+    /// Set outside of this GUI to AutoSplitterSettings XML, a complete .lss file, a JSON list of splits,
+    /// or the path of a file containing one of those.
+    /// The Splits below are replaced whenever it changes.
+    #[heading_level = 1]
+    import_splits: UglyText,
+    /// Export hksplitmaker
+    /// 
+    /// Set by the autosplitter to the Splits below as an hksplitmaker category,
+    /// using the segment names from the last import.
+    /// Also printed to the log whenever it changes.
+    #[heading_level = 1]
+    #[store_with = "export_hksplitmaker"]
+    export_hksplitmaker: SplitsExport,
+    /// Export Legacy Layout
+    /// 
+    /// Export AutoSplitterSettings in the Ordered, AutosplitStartRuns, and AutosplitEndRuns layout
+    /// from up through version 3 of LiveSplit.HollowKnight
+    export_legacy_layout: bool,
+    /// Export AutoSplitterSettings
+    /// 
+    /// Set by the autosplitter to the Splits below as AutoSplitterSettings XML,
+    /// for the LiveSplit.HollowKnight component on Windows.
+    /// Also printed to the log whenever it changes.
+    #[heading_level = 1]
+    #[store_with = "export_auto_splitter_settings"]
+    export_auto_splitter_settings: SplitsExport,
+    /// Export LSS
+    /// 
+    /// Set by the autosplitter to a skeleton .lss splits file for the Splits below,
+    /// using the segment names from the last import, or suggested names if those don't match.
+    /// Also printed to the log whenever it changes.
+    #[heading_level = 1]
+    #[store_with = "export_lss"]
+    export_lss: SplitsExport,
+    /// History
+    /// 
+    /// Undoes or redoes the last changes made in the settings GUI
+    #[heading_level = 1]
+    history: History,
     /// Splits
     #[heading_level = 1]
-    splits: UglyList<Split>,
+    splits: UglyList<SplitEntry>,
 }
 
-impl StoreGui for SettingsGui {
-    fn insert_into(&self, settings_map: &asr::settings::Map) -> bool {
-        self.splits.insert_into(settings_map, "splits")
+/// The splits, segment names, and whether to use the legacy layout, that an export is made from
+type ExportInputs = (Vec<Split>, Vec<String>, bool);
+
+/// Text that the autosplitter makes from the splits, shown like an UglyText,
+/// which keeps what it was last made from, so that it's only made again when that changes
+#[derive(Default)]
+struct SplitsExport {
+    text: UglyText,
+    made: RefCell<Option<(ExportInputs, String)>>,
+}
+
+impl Widget for SplitsExport {
+    type Args = UglyTextArgs;
+
+    fn register(key: &str, description: &str, args: Self::Args) -> Self {
+        SplitsExport { text: UglyText::register(key, description, args), made: RefCell::new(None) }
+    }
+
+    fn update_from(&mut self, settings_map: &asr::settings::Map, key: &str, args: Self::Args) {
+        self.text.update_from(settings_map, key, args);
     }
 }
 
+impl SplitsExport {
+    /// The text made from the inputs, which only makes it again if they changed since it was last made,
+    /// and prints it to the log if it differs from the stored text
+    fn made_from(&self, key: &str, inputs: ExportInputs, make: impl FnOnce(&ExportInputs) -> String) -> UglyText {
+        let mut made = self.made.borrow_mut();
+        let stale = match made.as_ref() {
+            Some((made_inputs, _)) => *made_inputs != inputs,
+            None => true,
+        };
+        if stale {
+            let text = make(&inputs);
+            if text != self.text.0 {
+                asr::print_message(&format!("{}: {}", key, text));
+            }
+            *made = Some((inputs, text));
+        }
+        UglyText(made.as_ref().map(|(_, text)| text.clone()).unwrap_or_default())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions, StoreWidget)]
+pub enum AutoReset {
+    /// Off
+    Off,
+    /// First Split
+    /// 
+    /// When the first split splits again, if it's Start New Game and no other split starts or ends the run
+    #[default]
+    FirstSplit,
+    /// New Save File
+    NewSaveFile,
+    /// Main Menu
+    MainMenu,
+    /// Scene
+    /// 
+    /// On a transition to the Auto Reset Scene
+    Scene,
+}
 
 impl SettingsGui {
     pub fn get_splits(&self) -> Vec<Split> {
-        self.splits.get_list().into_iter().map(|rb| rb.clone()).collect()
+        self.splits.get_list().into_iter().map(|e| e.split.split.clone()).collect()
+    }
+
+    /// The options of each split besides the split itself, at the same indices as get_splits
+    pub fn get_split_options(&self) -> Vec<SplitOptions> {
+        self.splits.get_list().into_iter().map(SplitEntry::options).collect()
+    }
+
+    pub fn get_auto_start(&self) -> bool {
+        self.auto_start
+    }
+
+    /// The split that resets the timer, if any, given the current splits
+    pub fn get_auto_reset_split(&self, splits: &[Split]) -> Option<Split> {
+        match self.auto_reset {
+            AutoReset::Off => None,
+            AutoReset::FirstSplit => splits::auto_reset_safe(splits).then(|| splits[0].clone()),
+            AutoReset::NewSaveFile => Some(Split::StartNewGame),
+            AutoReset::MainMenu => Some(Split::Menu),
+            AutoReset::Scene => Some(Split::SceneTransition { old: ScenePattern::Any, new: self.auto_reset_scene.clone() }),
+        }
+    }
+
+    /// Whether auto reset is allowed after `done` splits
+    pub fn auto_reset_allowed(&self, done: usize) -> bool {
+        let before = self.auto_reset_before_split.0;
+        before <= 0 || (done as i64) < before
+    }
+
+    pub fn get_comparisons(&self) -> bool {
+        self.live_stat_comparisons
+    }
+
+    pub fn get_live_stats(&self) -> Vec<LiveStat> {
+        [
+            (self.live_stat_geo, LiveStat::Geo),
+            (self.live_stat_health, LiveStat::Health),
+            (self.live_stat_masks, LiveStat::Masks),
+            (self.live_stat_soul_vessels, LiveStat::SoulVessels),
+            (self.live_stat_essence, LiveStat::Essence),
+            (self.live_stat_grubs, LiveStat::Grubs),
+            (self.live_stat_charms_owned, LiveStat::CharmsOwned),
+            (self.live_stat_completion, LiveStat::Completion),
+        ].into_iter().filter_map(|(enabled, stat)| enabled.then_some(stat)).collect()
     }
 
     pub async fn wait_load_merge_register() -> SettingsGui {
+        settings_migration::loop_load_migrate_store();
         let settings1 = asr::settings::Map::load();
         let auto_splitter_settings = include_str!("AutoSplitterSettings.txt");
-        let settings2 = XMLSettings::from_xml_string(auto_splitter_settings, &[("Splits", "Split")]).unwrap_or_default();
-        let splits2 = splits_from_settings(&settings2);
+        let splits2 = lss_splits_from_str(auto_splitter_settings).map(|l| l.splits).unwrap_or_else(splits::default_splits);
         if settings1.get("splits").is_some_and(|v| v.get_list().is_some_and(|l| !l.is_empty())) {
             asr::print_message("settings1: from asr::settings::Map::load");
         } else {
@@ -37,7 +258,9 @@ impl SettingsGui {
             let settings3 = asr::settings::Map::new();
             let l = asr::settings::List::new();
             for split in splits2.iter() {
-                l.push(split.to_string().as_str());
+                let item = asr::settings::Map::new();
+                item.insert("split", split.to_string().as_str());
+                l.push(&item);
             }
             settings3.insert("splits", &l);
             SettingsObject::wait_load_merge_store(&SettingsObject::Map(settings3)).await;
@@ -49,11 +272,201 @@ impl SettingsGui {
             asr::print_message("WARNING: splits from asr::settings::Map::load differ from AutoSplitterSettings.txt");
                 asr::print_message("assuming AutoSplitterSettings.txt is out of date, using asr::settings::Map::load");
         }
+        gui.switch_profile_if_changed();
+        gui.import_splits_if_changed();
+        gui.apply_split_preset();
+        gui.show_profile_names();
         gui
     }
+
+    /// Replaces the splits with the ones from import_splits,
+    /// if its contents have changed since the last import
+    pub fn import_splits_if_changed(&mut self) -> bool {
+        let Some(contents) = self.import_splits_contents() else { return false; };
+        let hash = stable_hash([contents.as_str()]);
+        if asr::settings::Map::load().get("imported_splits_hash").is_some_and(|v| v.get_i64() == Some(hash)) {
+            return false;
+        }
+        let Some(lss) = lss_splits_from_str(&contents) else {
+            asr::print_message("WARNING: could not import splits, expected AutoSplitterSettings, .lss, or JSON");
+            return false;
+        };
+        asr::print_message(&format!("imported splits: {:?}", lss.splits));
+        self.replace_splits(lss, |settings_map| settings_map.insert("imported_splits_hash", hash));
+        true
+    }
+
+    /// Replaces the splits with the chosen split preset, if there is one,
+    /// and sets the split preset back to None
+    pub fn apply_split_preset(&mut self) -> bool {
+        let preset = std::mem::take(&mut self.split_preset.0);
+        if preset == SplitPreset::None {
+            return false;
+        }
+        let lss = preset.lss_splits().unwrap_or_else(|| {
+            asr::print_message(&format!("WARNING: could not load split preset {}", options_description(&preset)));
+            self.get_lss_splits(&asr::settings::Map::load())
+        });
+        asr::print_message(&format!("split preset {}: {:?}", options_description(&preset), lss.splits));
+        let profile = self.profile.0;
+        self.replace_splits(lss, |settings_map| {
+            if profile.name(settings_map).is_none() {
+                profile.set_name(settings_map, options_description(&preset));
+            }
+        });
+        self.show_profile_names();
+        true
+    }
+
+    /// Keeps the splits in the profile they belong to, and loads the chosen profile's splits,
+    /// if another profile has been chosen since
+    pub fn switch_profile_if_changed(&mut self) -> bool {
+        let settings_map = asr::settings::Map::load();
+        let from = settings_map.get("active_profile").and_then(|v| v.get_string()).and_then(|s| options_value::<Profile>(&s)).unwrap_or_default();
+        let to = self.profile.0;
+        if from == to {
+            return false;
+        }
+        if asr::timer::state() != TimerState::NotRunning {
+            set_tooltip("profile", &format!("Switching to {} after the timer is reset", to.name_or_description(&settings_map)));
+            return false;
+        }
+        let lss = self.get_lss_splits(&settings_map);
+        let new_lss = to.load_splits(&settings_map).unwrap_or_else(|| lss.clone());
+        asr::print_message(&format!("profile {}: {:?}", to.name_or_description(&settings_map), new_lss.splits));
+        self.replace_splits(new_lss, |settings_map| {
+            from.save(settings_map, &lss);
+            to.load_split_times(settings_map);
+            settings_map.insert("active_profile", options_str(&to));
+        });
+        set_tooltip("profile", &to.name_or_description(&asr::settings::Map::load()));
+        self.show_profile_names();
+        true
+    }
+
+    /// Shows the names of the profiles as tooltips on their options
+    pub fn show_profile_names(&self) {
+        let settings_map = asr::settings::Map::load();
+        for o in Profile::radio_button_options() {
+            set_tooltip(&format!("profile_{}", o.key), &o.value.name_or_description(&settings_map));
+        }
+    }
+
+    /// Replaces the splits and the segment names, and stores them along with anything `also_insert` inserts
+    fn replace_splits(&mut self, lss: LssSplits, also_insert: impl Fn(&asr::settings::Map)) {
+        let mut args = UglyListArgs::default();
+        args.set_heading_level(1);
+        self.splits.set_list("splits", args, lss.splits.into_iter().map(SplitEntry::from).collect());
+        let segment_names = asr::settings::List::new();
+        for name in lss.segment_names.iter() {
+            segment_names.push(name.as_str());
+        }
+        loop {
+            let settings_map = asr::settings::Map::load();
+            let old = settings_map.clone();
+            settings_map.insert("segment_names", &segment_names);
+            self.insert_into(&settings_map);
+            also_insert(&settings_map);
+            if settings_map.store_if_unchanged(&old) {
+                break;
+            }
+        }
+    }
+
+    /// The splits with the segment names from the last import,
+    /// or suggested segment names if those don't match the splits,
+    /// except for the splits with a Name of their own
+    fn get_lss_splits(&self, settings_map: &asr::settings::Map) -> LssSplits {
+        let segment_names: Vec<String> = settings_map.get("segment_names").and_then(|v| v.get_list()).map(|l| {
+            l.iter().filter_map(|v| v.get_string()).collect()
+        }).unwrap_or_default();
+        let lss = LssSplits { segment_names, splits: self.get_splits() };
+        let mut lss = if lss.is_valid() { lss } else { LssSplits::from_splits(lss.splits) };
+        // the first split starts the timer, so each segment ends with the split after it
+        for (segment_name, entry) in lss.segment_names.iter_mut().zip(self.splits.get_list().into_iter().skip(1)) {
+            let name = entry.name.0.trim();
+            if !name.is_empty() {
+                *segment_name = name.to_string();
+            }
+        }
+        lss
+    }
+
+    /// The splits as an hksplitmaker category
+    fn export_hksplitmaker(&self, settings_map: &asr::settings::Map) -> UglyText {
+        let lss = self.get_lss_splits(settings_map);
+        self.export_hksplitmaker.made_from("export_hksplitmaker", (lss.splits, lss.segment_names, false), |(splits, segment_names, _)| {
+            HKSplitMakerCategory::from_splits("Custom", splits, segment_names).to_json_string()
+        })
+    }
+
+    /// The splits as AutoSplitterSettings XML
+    fn export_auto_splitter_settings(&self, _settings_map: &asr::settings::Map) -> UglyText {
+        self.export_auto_splitter_settings.made_from("export_auto_splitter_settings", (self.get_splits(), vec![], self.export_legacy_layout), |(splits, _, legacy_layout)| {
+            splits_to_settings_xml(splits, *legacy_layout)
+        })
+    }
+
+    /// The splits as a skeleton .lss file
+    fn export_lss(&self, settings_map: &asr::settings::Map) -> UglyText {
+        let lss = self.get_lss_splits(settings_map);
+        self.export_lss.made_from("export_lss", (lss.splits, lss.segment_names, false), |(splits, segment_names, _)| {
+            LssSplits { segment_names: segment_names.clone(), splits: splits.clone() }.to_lss_string("Custom")
+        })
+    }
+
+    /// Shows the warnings as tooltips on the splits they are about, clearing the tooltips of the others
+    pub fn show_split_warnings(&self, warnings: &[SplitWarning]) {
+        for i in 0..self.get_splits().len() {
+            let messages: Vec<&str> = warnings.iter().filter(|w| w.index == i).map(|w| w.message.as_str()).collect();
+            set_tooltip(&format!("splits_{}_item", i), &messages.join("\n"));
+        }
+    }
+
+    /// The text of import_splits, or the contents of the file at that path
+    fn import_splits_contents(&self) -> Option<String> {
+        let s = self.import_splits.0.trim();
+        if s.is_empty() {
+            None
+        } else if s.starts_with(['<', '[', '{']) {
+            Some(s.to_string())
+        } else {
+            std::fs::read_to_string(s).ok()
+        }
+    }
 }
 
 
+/// The splits from the contents of an AutoSplitterSettings element, a complete `.lss` splits file,
+/// an hksplitmaker category, or a JSON list of splits,
+/// along with the segment names for the formats that have them
+fn lss_splits_from_str(s: &str) -> Option<LssSplits> {
+    let s = s.trim_start_matches('\u{feff}').trim();
+    if s.starts_with('[') {
+        let values: Vec<serde_json::Value> = serde_json::from_str(s).ok()?;
+        let splits = values.into_iter().map(|v| match v {
+            serde_json::Value::String(name) => Split::from_str(&name).ok(),
+            v => serde_json::from_value(v).ok(),
+        }).collect::<Option<Vec<Split>>>()?;
+        return Some(LssSplits { segment_names: vec![], splits });
+    }
+    if s.starts_with('{') {
+        return Some(HKSplitMakerCategory::from_json_string(s)?.to_lss_splits());
+    }
+    if let Some(lss) = LssSplits::from_lss_string(s) {
+        if !lss.is_valid() {
+            asr::print_message(&format!(
+                "WARNING: {} autosplits for {} segments, expected 1 to start plus 1 per segment",
+                lss.splits.len(),
+                lss.segment_names.len()));
+        }
+        return Some(lss);
+    }
+    let settings = XMLSettings::from_xml_string(s, &[("Splits", "Split")]).ok()?;
+    let settings = settings.dict_get("AutoSplitterSettings").unwrap_or(settings);
+    Some(LssSplits { segment_names: vec![], splits: splits_from_settings(&settings) })
+}
+
 pub fn splits_from_settings<S: Settings>(s: &S) -> Vec<Split> {
     let maybe_ordered = s.dict_get("Ordered");
     let maybe_start = s.dict_get("AutosplitStartRuns");
@@ -79,6 +492,55 @@ pub fn splits_from_settings<S: Settings>(s: &S) -> Vec<Split> {
     }
 }
 
+/// The AutoSplitterSettings element from `splits_to_settings_element` as XML
+pub fn splits_to_settings_xml(splits: &[Split], legacy: bool) -> String {
+    element_to_string(&splits_to_settings_element(splits, legacy))
+}
+
+/// An AutoSplitterSettings element with the splits, in either the layout from after version 4,
+/// or the legacy layout from up through version 3, that `splits_from_settings` can read back
+pub fn splits_to_settings_element(splits: &[Split], legacy: bool) -> Element {
+    let mut settings = Element::new("AutoSplitterSettings");
+    let mut splits_element = Element::new("Splits");
+    let splits = if legacy {
+        let (start, rest) = splits.split_first().unwrap_or((&Split::StartNewGame, &[]));
+        let (end, rest) = match rest.split_last() {
+            Some((Split::EndingSplit, rest)) => (false, rest),
+            _ => (true, rest),
+        };
+        let start = if start == &Split::StartNewGame { "".to_string() } else { start.to_string() };
+        settings.children.push(XMLNode::Element(text_element("Ordered", "True")));
+        settings.children.push(XMLNode::Element(text_element("AutosplitEndRuns", if end { "True" } else { "False" })));
+        settings.children.push(XMLNode::Element(text_element("AutosplitStartRuns", &start)));
+        rest
+    } else {
+        splits
+    };
+    for split in splits {
+        splits_element.children.push(XMLNode::Element(text_element("Split", &split.to_string())));
+    }
+    settings.children.push(XMLNode::Element(splits_element));
+    settings
+}
+
+/// The element as indented XML, without a document declaration
+pub fn element_to_string(e: &Element) -> String {
+    let mut bytes = Vec::new();
+    let config = EmitterConfig::new().perform_indent(true).write_document_declaration(false);
+    if e.write_with_config(&mut bytes, config).is_err() {
+        return String::new();
+    }
+    String::from_utf8(bytes).unwrap_or_default()
+}
+
+pub fn text_element(name: &str, text: &str) -> Element {
+    let mut e = Element::new(name);
+    if !text.is_empty() {
+        e.children.push(XMLNode::Text(text.to_string()));
+    }
+    e
+}
+
 fn splits_from_settings_split_list<S: Settings>(s: &S) -> Vec<Split> {
     s.as_list().unwrap_or_default().into_iter().filter_map(Split::from_settings_split).collect()
 }
@@ -0,0 +1,31 @@
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// A 64-bit FNV-1a hash of the strings, each followed by a 0xff byte like `str::hash` does,
+/// for hashes stored in the settings, since unlike DefaultHasher it stays the same across Rust releases
+pub fn stable_hash<'a>(strings: impl IntoIterator<Item = &'a str>) -> i64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    for s in strings {
+        for b in s.bytes().chain([0xff]) {
+            hash ^= b as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+    hash as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable_hash_values() {
+        assert_eq!(stable_hash([]), -3750763034362895579);
+        assert_eq!(stable_hash(["StartNewGame", "EndingSplit"]), -3520741390928556452);
+    }
+
+    #[test]
+    fn stable_hash_separates_strings() {
+        assert_ne!(stable_hash(["ab", "c"]), stable_hash(["a", "bc"]));
+    }
+}
@@ -0,0 +1,222 @@
+/// The version of the layout of the settings that this autosplitter reads and writes,
+/// stored in `settings_version`, where settings from before there was a version are version 0.
+/// Version 1 only adds the version, since UglyList fills in the item keys missing from version 0 lists.
+/// Version 2 makes each item of the splits list a SplitEntry map, with the split under `split`.
+pub const SETTINGS_VERSION: i64 = 2;
+
+/// The prefix of the keys of the backups of the settings from before each migration, like `settings_backup_v0`
+const BACKUP_PREFIX: &str = "settings_backup_v";
+
+/// Split keys that were renamed, as the settings version that renamed them, the old key, and the new key,
+/// so that the splits saved under the old key aren't dropped from the lists they're in,
+/// or from imported splits and profiles, which Split::from_str renames with renamed_split.
+/// Renaming a Split variant needs an entry here along with a new SETTINGS_VERSION.
+const RENAMED_SPLITS: &[(i64, &str, &str)] = &[];
+
+/// Upgrades the settings to SETTINGS_VERSION if they're from an older version,
+/// keeping a backup of the settings from before, and storing them
+pub fn loop_load_migrate_store() {
+    loop {
+        let settings_map = asr::settings::Map::load();
+        let old = settings_map.clone();
+        let version = settings_version(&settings_map);
+        if SETTINGS_VERSION < version {
+            asr::print_message(&format!("WARNING: settings version {} is newer than this autosplitter's {}", version, SETTINGS_VERSION));
+            return;
+        }
+        if version == SETTINGS_VERSION {
+            return;
+        }
+        migrate(&settings_map, version);
+        if settings_map.store_if_unchanged(&old) {
+            if !old.is_empty() {
+                asr::print_message(&format!("migrated settings from version {} to {}, with a backup in {}{}", version, SETTINGS_VERSION, BACKUP_PREFIX, version));
+            }
+            return;
+        }
+    }
+}
+
+fn settings_version(settings_map: &asr::settings::Map) -> i64 {
+    settings_map.get("settings_version").and_then(|v| v.get_i64()).unwrap_or(0)
+}
+
+/// Upgrades the settings from `version` to SETTINGS_VERSION,
+/// after keeping a backup of them unless they're empty
+fn migrate(settings_map: &asr::settings::Map, version: i64) {
+    if !settings_map.is_empty() {
+        let backup = asr::settings::Map::new();
+        for (k, v) in settings_map.iter().filter(|(k, _)| !k.starts_with(BACKUP_PREFIX)) {
+            backup.insert(&k, &v);
+        }
+        settings_map.insert(&format!("{}{}", BACKUP_PREFIX, version), &backup);
+    }
+    // versions that change more than split keys add their steps after the renames here
+    rename_splits(settings_map, RENAMED_SPLITS, version);
+    if version < 2 {
+        split_entries(settings_map);
+    }
+    settings_map.insert("settings_version", SETTINGS_VERSION);
+}
+
+/// Makes each split in the splits list a map with the split under `split`, like a SplitEntry stores it,
+/// and moves the settings of each split item under `splits_{i}_item_split`
+fn split_entries(settings_map: &asr::settings::Map) {
+    if let Some(list) = settings_map.get("splits").and_then(|v| v.get_list()) {
+        let new_list = asr::settings::List::new();
+        for item in list.iter() {
+            match item.get_string() {
+                Some(split) => {
+                    let entry = split_entry_map(&split);
+                    new_list.push(&entry);
+                }
+                None => new_list.push(&item),
+            }
+        }
+        settings_map.insert("splits", &new_list);
+    }
+    let items: Vec<(String, String)> = settings_map.iter().filter_map(|(k, v)| {
+        let i = k.strip_prefix("splits_")?.strip_suffix("_item")?;
+        i.parse::<usize>().ok()?;
+        Some((k, v.get_string()?))
+    }).collect();
+    for (key_item, split) in items {
+        let key_item_ = format!("{}_", key_item);
+        let key_split = format!("{}_split", key_item);
+        let data: Vec<(String, asr::settings::Value)> = settings_map.iter().filter(|(k, _)| k.starts_with(&key_item_)).collect();
+        for (k, v) in data {
+            let new_k = format!("{}{}", key_split, &k[key_item.len()..]);
+            if settings_map.get(&new_k).is_none() {
+                settings_map.insert(&new_k, &v);
+            }
+        }
+        settings_map.insert(&key_split, split.as_str());
+        let entry = split_entry_map(&split);
+        settings_map.insert(&key_item, &entry);
+    }
+}
+
+fn split_entry_map(split: &str) -> asr::settings::Map {
+    let map = asr::settings::Map::new();
+    map.insert("split", split);
+    map
+}
+
+/// Replaces the split keys renamed after `version` in the lists of splits and in the split items of the settings list
+fn rename_splits(settings_map: &asr::settings::Map, renames: &[(i64, &str, &str)], version: i64) {
+    if !renames.iter().any(|(v, _, _)| version < *v) {
+        return;
+    }
+    let renamed = |s: &str| renamed_after(renames, version, s);
+    for (k, v) in settings_map.iter() {
+        let is_list_of_splits = k == "splits" || (k.starts_with("split_profile_") && k.ends_with("_splits"));
+        let is_split_item = k.starts_with("splits_") && (k.ends_with("_item") || k.ends_with("_item_split"));
+        if let (true, Some(list)) = (is_list_of_splits, v.get_list()) {
+            let new_list = asr::settings::List::new();
+            for item in list.iter() {
+                // the items of the splits list are SplitEntry maps from version 2 on
+                let entry = item.get_map();
+                let split = entry.as_ref().map_or_else(|| item.get_string(), |m| m.get("split").and_then(|v| v.get_string()));
+                match (split.as_deref().and_then(renamed), entry) {
+                    (Some(new), Some(m)) => {
+                        let new_m = m.clone();
+                        new_m.insert("split", new.as_str());
+                        new_list.push(&new_m);
+                    }
+                    (Some(new), None) => new_list.push(new.as_str()),
+                    (None, _) => new_list.push(&item),
+                }
+            }
+            settings_map.insert(&k, &new_list);
+        } else if let (true, Some(new)) = (is_split_item, v.get_string().as_deref().and_then(renamed)) {
+            settings_map.insert(&k, new.as_str());
+        }
+    }
+}
+
+/// The current key of a split saved under a key that has been renamed since, or None if it hasn't been renamed
+pub fn renamed_split(s: &str) -> Option<String> {
+    renamed_after(RENAMED_SPLITS, 0, s)
+}
+
+/// The split with the keys renamed after `version` replaced, following renames of renamed keys,
+/// where the split is a key, or a JSON object with the key of a split that holds data
+fn renamed_after(renames: &[(i64, &str, &str)], version: i64, s: &str) -> Option<String> {
+    if !renames.iter().any(|(v, _, _)| version < *v) {
+        return None;
+    }
+    let rename_key = |old: &str| -> Option<&str> {
+        let mut key = None;
+        for (_, rename_old, rename_new) in renames.iter().filter(|(v, _, _)| version < *v) {
+            if key.unwrap_or(old) == *rename_old {
+                key = Some(*rename_new);
+            }
+        }
+        key
+    };
+    if !s.starts_with('{') {
+        return rename_key(s).map(str::to_string);
+    }
+    let serde_json::Value::Object(object) = serde_json::from_str(s).ok()? else { return None; };
+    let (old, data) = object.iter().next().filter(|_| object.len() == 1)?;
+    let mut new_object = serde_json::Map::new();
+    new_object.insert(rename_key(old)?.to_string(), data.clone());
+    Some(serde_json::Value::Object(new_object).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const RENAMES: &[(i64, &str, &str)] = &[(2, "Old", "Middle"), (3, "Middle", "New"), (3, "OtherOld", "OtherNew")];
+
+    #[test]
+    fn renamed_after_follows_renames() {
+        assert_eq!(renamed_after(RENAMES, 0, "Old").as_deref(), Some("New"));
+        assert_eq!(renamed_after(RENAMES, 2, "Old"), None);
+        assert_eq!(renamed_after(RENAMES, 2, "Middle").as_deref(), Some("New"));
+        assert_eq!(renamed_after(RENAMES, 1, "OtherOld").as_deref(), Some("OtherNew"));
+        assert_eq!(renamed_after(RENAMES, 3, "OtherOld"), None);
+        assert_eq!(renamed_after(RENAMES, 0, "Unrenamed"), None);
+    }
+
+    #[test]
+    fn renamed_after_renames_data_splits() {
+        assert_eq!(renamed_after(RENAMES, 0, r#"{"Old":{"field":"geo","value":1}}"#).as_deref(), Some(r#"{"New":{"field":"geo","value":1}}"#));
+        assert_eq!(renamed_after(RENAMES, 0, r#"{"Unrenamed":"None"}"#), None);
+        assert_eq!(renamed_after(RENAMES, 0, "{not json"), None);
+    }
+
+    #[test]
+    fn migrate_split_entries() {
+        let settings_map = asr::settings::Map::new();
+        let list = asr::settings::List::new();
+        list.push("StartNewGame");
+        list.push("EnterMapZone");
+        settings_map.insert("splits", &list);
+        settings_map.insert("splits_1_item", "EnterMapZone");
+        settings_map.insert("splits_1_item_map_zone", "CITY");
+        settings_map.insert("settings_version", 1_i64);
+        migrate(&settings_map, 1);
+
+        let list = settings_map.get("splits").and_then(|v| v.get_list()).expect("the splits should be a list");
+        let splits: Vec<String> = list.iter().filter_map(|v| v.get_map()?.get("split")?.get_string()).collect();
+        assert_eq!(splits, ["StartNewGame", "EnterMapZone"]);
+        let item = settings_map.get("splits_1_item").and_then(|v| v.get_map()).expect("the item should be a map");
+        assert_eq!(item.get("split").and_then(|v| v.get_string()).as_deref(), Some("EnterMapZone"));
+        assert_eq!(settings_map.get("splits_1_item_split").and_then(|v| v.get_string()).as_deref(), Some("EnterMapZone"));
+        assert_eq!(settings_map.get("splits_1_item_split_map_zone").and_then(|v| v.get_string()).as_deref(), Some("CITY"));
+        assert_eq!(settings_version(&settings_map), SETTINGS_VERSION);
+        assert!(settings_map.get("settings_backup_v1").is_some());
+    }
+
+    #[test]
+    fn renamed_splits_are_current() {
+        for (v, _, new) in RENAMED_SPLITS {
+            assert!(*v <= SETTINGS_VERSION);
+            assert!(crate::splits::Split::from_str(new).is_ok(), "{} is not a split", new);
+        }
+    }
+}
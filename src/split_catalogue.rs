@@ -0,0 +1,35 @@
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct CatalogueEntry {
+    pub key: String,
+    pub region: String,
+}
+
+/// The entries of split_catalogue.json, by key
+pub fn catalogue() -> &'static BTreeMap<String, CatalogueEntry> {
+    static CATALOGUE: OnceLock<BTreeMap<String, CatalogueEntry>> = OnceLock::new();
+    CATALOGUE.get_or_init(|| {
+        let entries: Vec<CatalogueEntry> = serde_json::from_str(include_str!("split_catalogue.json")).expect("split_catalogue.json should be a list of catalogue entries");
+        entries.into_iter().map(|e| (e.key.clone(), e)).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ugly_widget::radio_button::options_value;
+
+    use super::*;
+    use crate::splits::Split;
+
+    #[test]
+    fn catalogue_keys_are_splits() {
+        assert!(!catalogue().is_empty());
+        for key in catalogue().keys() {
+            assert!(options_value::<Split>(key).is_some(), "{} is not a split", key);
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use ugly_widget::radio_button::RadioButtonOptions;
+
+use crate::hksplitmaker::HKSplitMakerCategory;
+use crate::lss::LssSplits;
+use crate::splits::Split;
+
+/// Built-in split lists for common categories, as a starting point to adjust to a route
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum SplitPreset {
+    /// None
+    ///
+    /// Leaves the Splits alone
+    #[default]
+    None,
+    /// Any%
+    ///
+    /// The skills and Dreamers needed to reach The Hollow Knight ending
+    AnyPercent,
+    /// All Skills
+    ///
+    /// Every spell, movement ability, and nail art, ending on the last one
+    AllSkills,
+    /// True Ending
+    ///
+    /// Any% plus Void Heart, ending with Dream No More
+    TrueEnding,
+    /// 112%
+    ///
+    /// Milestones on the way to 112% completion
+    OneHundredTwelvePercent,
+    /// Pantheon of Hallownest
+    ///
+    /// From entering Pantheon 5 to Absolute Radiance, split at the benches
+    PantheonOfHallownest,
+    /// All Bosses
+    ///
+    /// Every boss outside of Godhome, ending when the credits roll
+    AllBosses,
+    /// Low%
+    ///
+    /// Only the skills and Dreamers that can't be skipped on the way to The Hollow Knight ending
+    LowPercent,
+    /// 4 Mask Shards
+    ///
+    /// The category extension from hksplitmaker.json
+    FourMaskShards,
+}
+
+impl SplitPreset {
+    /// The splits of the preset with their segment names, or None for SplitPreset::None
+    pub fn lss_splits(&self) -> Option<LssSplits> {
+        let splits = match self {
+            SplitPreset::None => return None,
+            SplitPreset::FourMaskShards => {
+                return HKSplitMakerCategory::from_json_string(include_str!("../hksplitmaker.json")).map(|c| c.to_lss_splits());
+            }
+            SplitPreset::AnyPercent => vec![
+                Split::StartNewGame,
+                Split::VengefulSpirit,
+                Split::Hornet1,
+                Split::MothwingCloak,
+                Split::MantisClaw,
+                Split::CrystalHeart,
+                Split::DreamNail,
+                Split::Uumuu,
+                Split::Monomon,
+                Split::Lurien,
+                Split::Hegemol,
+                Split::EndingA,
+            ],
+            SplitPreset::AllSkills => vec![
+                Split::StartNewGame,
+                Split::VengefulSpirit,
+                Split::MothwingCloak,
+                Split::MantisClaw,
+                Split::CrystalHeart,
+                Split::DesolateDive,
+                Split::MonarchWings,
+                Split::HowlingWraiths,
+                Split::IsmasTear,
+                Split::ShadeSoul,
+                Split::DreamNail,
+                Split::DescendingDark,
+                Split::ShadeCloak,
+                Split::AbyssShriek,
+                Split::CycloneSlash,
+                Split::DashSlash,
+                Split::GreatSlash,
+            ],
+            SplitPreset::TrueEnding => vec![
+                Split::StartNewGame,
+                Split::VengefulSpirit,
+                Split::Hornet1,
+                Split::MothwingCloak,
+                Split::MantisClaw,
+                Split::CrystalHeart,
+                Split::MonarchWings,
+                Split::DreamNail,
+                Split::Uumuu,
+                Split::Monomon,
+                Split::Lurien,
+                Split::Hegemol,
+                Split::Hornet2,
+                Split::KingsBrand,
+                Split::ShadeCloak,
+                Split::WhiteFragmentLeft,
+                Split::WhiteFragmentRight,
+                Split::VoidHeart,
+                Split::HollowKnightDreamnail,
+                Split::EndingC,
+            ],
+            SplitPreset::OneHundredTwelvePercent => vec![
+                Split::StartNewGame,
+                Split::VengefulSpirit,
+                Split::MothwingCloak,
+                Split::MantisClaw,
+                Split::CrystalHeart,
+                Split::DesolateDive,
+                Split::MonarchWings,
+                Split::IsmasTear,
+                Split::DreamNail,
+                Split::ShadeCloak,
+                Split::KingsBrand,
+                Split::Dreamer1,
+                Split::Dreamer2,
+                Split::Dreamer3,
+                Split::ColosseumGold,
+                Split::NightmareKingGrimm,
+                Split::VoidHeart,
+                Split::Pantheon1,
+                Split::Pantheon2,
+                Split::Pantheon3,
+                Split::Pantheon4,
+                Split::Completion112,
+                Split::EndingSplit,
+            ],
+            SplitPreset::PantheonOfHallownest => vec![
+                Split::Pantheon5Entry,
+                Split::Hornet1P,
+                Split::MantisLordsP,
+                Split::SheoPaintmasterP,
+                Split::TroupeMasterGrimmP,
+                Split::NightmareKingGrimmP,
+                Split::PureVesselP,
+                Split::RadianceP,
+            ],
+            SplitPreset::AllBosses => vec![
+                Split::StartNewGame,
+                Split::FalseKnight,
+                Split::GruzMother,
+                Split::Hornet1,
+                Split::MegaMossCharger,
+                Split::BroodingMawlek,
+                Split::MantisLords,
+                Split::SoulMaster,
+                Split::DungDefender,
+                Split::CrystalGuardian1,
+                Split::BrokenVessel,
+                Split::Gorb,
+                Split::Xero,
+                Split::ElderHu,
+                Split::Nosk,
+                Split::BlackKnight,
+                Split::Collector,
+                Split::Flukemarm,
+                Split::Uumuu,
+                Split::Marmu,
+                Split::Hornet2,
+                Split::TraitorLord,
+                Split::HiveKnight,
+                Split::CrystalGuardian2,
+                Split::GodTamer,
+                Split::NoEyes,
+                Split::Galien,
+                Split::Markoth,
+                Split::TroupeMasterGrimm,
+                Split::NightmareKingGrimm,
+                Split::GreyPrince,
+                Split::FailedKnight,
+                Split::SoulTyrant,
+                Split::LostKin,
+                Split::WhiteDefender,
+                Split::EndingSplit,
+            ],
+            SplitPreset::LowPercent => vec![
+                Split::StartNewGame,
+                Split::VengefulSpirit,
+                Split::MothwingCloak,
+                Split::MantisClaw,
+                Split::DreamNail,
+                Split::Uumuu,
+                Split::Monomon,
+                Split::Lurien,
+                Split::Hegemol,
+                Split::EndingA,
+            ],
+        };
+        Some(LssSplits::from_splits(splits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_are_valid() {
+        assert!(SplitPreset::None.lss_splits().is_none());
+        for o in SplitPreset::radio_button_options() {
+            if o.value == SplitPreset::None {
+                continue;
+            }
+            let lss_splits = o.value.lss_splits().unwrap_or_else(|| panic!("no splits for preset {}", o.key));
+            assert!(lss_splits.is_valid(), "preset {} has {} splits for {} segments", o.key, lss_splits.splits.len(), lss_splits.segment_names.len());
+        }
+    }
+
+    #[test]
+    fn four_mask_shards_preset() {
+        let lss_splits = SplitPreset::FourMaskShards.lss_splits().expect("hksplitmaker.json should parse");
+        assert_eq!(lss_splits.splits.len(), 14);
+        assert_eq!(lss_splits.splits[..3], [Split::StartNewGame, Split::KingsPass, Split::Grub1]);
+        assert_eq!(lss_splits.splits.last(), Some(&Split::Mask1));
+        assert_eq!(lss_splits.segment_names[0], Split::KingsPass.default_segment_name());
+        assert_eq!(lss_splits.segment_names[1], format!("{} - Vengefly Pogo", Split::Grub1.default_segment_name()));
+        assert_eq!(lss_splits.segment_names.last().map(String::as_str), Some("Shard 4 - Sly"));
+    }
+}
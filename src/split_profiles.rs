@@ -0,0 +1,121 @@
+use std::str::FromStr;
+
+use asr::settings::gui::{add_bool, add_title, Widget};
+use ugly_widget::radio_button::{options_description, RadioButton, RadioButtonArgs, RadioButtonOptions};
+use ugly_widget::store::StoreWidget;
+
+use crate::lss::LssSplits;
+use crate::splits::Split;
+
+/// The settings that split_times.rs keeps for the current splits, which each profile has its own copy of
+const SPLIT_TIMES_KEYS: [&str; 3] = ["split_times_hash", "personal_best_split_times", "best_segments"];
+
+/// Slots for named split lists, each with its own segment names and split times
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum Profile {
+    /// Profile 1
+    #[default]
+    Profile1,
+    /// Profile 2
+    Profile2,
+    /// Profile 3
+    Profile3,
+    /// Profile 4
+    Profile4,
+}
+
+/// The profile options like a RadioButton, but with the names the profiles have when it's registered
+/// in the descriptions of their options, like `Profile 1: Any% NMG`
+#[derive(Clone)]
+pub struct ProfileButtons(pub Profile);
+
+impl Widget for ProfileButtons {
+    type Args = RadioButtonArgs<'static>;
+
+    fn register(key: &str, description: &str, args: Self::Args) -> Self {
+        let settings_map = asr::settings::Map::load();
+        add_title(key, description, args.heading_level);
+        for o in Profile::radio_button_options() {
+            let option_description = match o.value.name(&settings_map) {
+                Some(name) => format!("{}: {}", o.description, name),
+                None => o.description.to_string(),
+            };
+            add_bool(&format!("{}_{}", key, o.key), &option_description, o.value == Profile::default());
+        }
+        let mut buttons = ProfileButtons(Profile::default());
+        buttons.update_from(&settings_map, key, args);
+        buttons
+    }
+
+    fn update_from(&mut self, settings_map: &asr::settings::Map, key: &str, args: Self::Args) {
+        let mut rb = RadioButton(self.0);
+        rb.update_from(settings_map, key, args);
+        self.0 = rb.0;
+    }
+}
+
+impl StoreWidget for ProfileButtons {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        RadioButton(self.0).insert_into(settings_map, key)
+    }
+}
+
+impl Profile {
+    /// The key of a setting kept for this profile, like `split_profile_1_splits`
+    fn key(&self, suffix: &str) -> String {
+        format!("split_profile_{}_{}", *self as usize + 1, suffix)
+    }
+
+    /// The name set in `split_profile_N_name`, if there is one
+    pub fn name(&self, settings_map: &asr::settings::Map) -> Option<String> {
+        settings_map.get(&self.key("name")).and_then(|v| v.get_string()).filter(|s| !s.trim().is_empty())
+    }
+
+    /// The name, or the description of the slot if it doesn't have one
+    pub fn name_or_description(&self, settings_map: &asr::settings::Map) -> String {
+        self.name(settings_map).unwrap_or_else(|| options_description(self).to_string())
+    }
+
+    pub fn set_name(&self, settings_map: &asr::settings::Map, name: &str) {
+        settings_map.insert(&self.key("name"), name);
+    }
+
+    /// Keeps a copy of the splits, the segment names, and the split times of the current splits in this profile
+    pub fn save(&self, settings_map: &asr::settings::Map, lss: &LssSplits) {
+        let splits = asr::settings::List::new();
+        for split in lss.splits.iter() {
+            splits.push(split.to_string().as_str());
+        }
+        settings_map.insert(&self.key("splits"), &splits);
+        let segment_names = asr::settings::List::new();
+        for name in lss.segment_names.iter() {
+            segment_names.push(name.as_str());
+        }
+        settings_map.insert(&self.key("segment_names"), &segment_names);
+        for k in SPLIT_TIMES_KEYS {
+            if let Some(v) = settings_map.get(k) {
+                settings_map.insert(&self.key(k), &v);
+            }
+        }
+    }
+
+    /// The splits and segment names saved in this profile, or None if it hasn't been saved yet
+    pub fn load_splits(&self, settings_map: &asr::settings::Map) -> Option<LssSplits> {
+        let splits: Vec<Split> = settings_map.get(&self.key("splits"))?.get_list()?.iter().filter_map(|v| {
+            Split::from_str(&v.get_string()?).ok()
+        }).collect();
+        let segment_names = settings_map.get(&self.key("segment_names")).and_then(|v| v.get_list()).map(|l| {
+            l.iter().filter_map(|v| v.get_string()).collect()
+        }).unwrap_or_default();
+        Some(LssSplits { segment_names, splits })
+    }
+
+    /// Replaces the split times of the current splits with the ones saved in this profile, if it has any
+    pub fn load_split_times(&self, settings_map: &asr::settings::Map) {
+        for k in SPLIT_TIMES_KEYS {
+            if let Some(v) = settings_map.get(&self.key(k)) {
+                settings_map.insert(k, &v);
+            }
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use std::time::{Duration, Instant};
+
+use crate::settings_hash::stable_hash;
+use crate::splits::Split;
+
+// --------------------------------------------------------
+
+/// Tracks the game time of each split in the current run,
+/// and remembers the personal best split times and the best segments across runs,
+/// storing them in the settings along with a hash of the splits they are for.
+pub struct SplitTimes {
+    splits_hash: i64,
+    last_tick: Option<Instant>,
+    game_time: Duration,
+    /// Game times of the splits after the start in the current run
+    run: Vec<Duration>,
+    /// Game times of the splits after the start in the personal best
+    personal_best: Vec<Duration>,
+    best_segments: Vec<Option<Duration>>,
+}
+
+impl SplitTimes {
+    pub fn new(splits: &[Split]) -> SplitTimes {
+        let mut split_times = SplitTimes {
+            splits_hash: 0,
+            last_tick: None,
+            game_time: Duration::ZERO,
+            run: vec![],
+            personal_best: vec![],
+            best_segments: vec![],
+        };
+        split_times.set_splits(splits);
+        split_times
+    }
+
+    /// Loads the times stored for the splits, or forgets the times if they were for different splits,
+    /// keeping the times of the current run, since the splits can change while the timer is running
+    pub fn set_splits(&mut self, splits: &[Split]) {
+        let splits_hash = splits_hash(splits);
+        if splits_hash == self.splits_hash { return; }
+        self.splits_hash = splits_hash;
+        let settings_map = asr::settings::Map::load();
+        let stored_hash = settings_map.get("split_times_hash").and_then(|v| v.get_i64());
+        if stored_hash == Some(splits_hash) {
+            self.personal_best = load_millis(&settings_map, "personal_best_split_times").into_iter().flatten().collect();
+            self.best_segments = load_millis(&settings_map, "best_segments");
+        } else {
+            self.personal_best.clear();
+            self.best_segments.clear();
+        }
+    }
+
+    /// Loads the times stored for the splits again, even if the splits are the same,
+    /// such as after switching to a profile with its own times
+    pub fn reload(&mut self, splits: &[Split]) {
+        self.splits_hash = 0;
+        self.set_splits(splits);
+    }
+
+    /// Counts the time since the last tick as game time, unless game time is paused
+    pub fn tick(&mut self, game_time_paused: bool) {
+        let now = Instant::now();
+        if let Some(last_tick) = self.last_tick.filter(|_| !game_time_paused) {
+            self.game_time += now.saturating_duration_since(last_tick);
+        }
+        self.last_tick = Some(now);
+    }
+
+    pub fn start(&mut self) {
+        self.game_time = Duration::ZERO;
+        self.run.clear();
+    }
+
+    pub fn reset(&mut self) {
+        self.run.clear();
+    }
+
+    /// Records a split, out of `segments` in the whole run,
+    /// updating the best segment, and the personal best if the run is finished
+    pub fn split(&mut self, segments: usize) {
+        let segment = self.game_time.saturating_sub(self.run.last().copied().unwrap_or_default());
+        let i = self.run.len();
+        self.run.push(self.game_time);
+        if self.best_segments.len() <= i {
+            self.best_segments.resize(i + 1, None);
+        }
+        if self.best_segments[i].is_none_or(|best| segment < best) {
+            self.best_segments[i] = Some(segment);
+        }
+        let finished = segments <= self.run.len();
+        let faster = self.personal_best.len() < self.run.len()
+            || self.personal_best.get(i).is_some_and(|&pb| self.game_time < pb);
+        if finished && faster {
+            self.personal_best = self.run.clone();
+        }
+        self.store();
+    }
+
+    /// Records a skipped split, which has no time of its own, so the next segment starts at the split before it
+    pub fn skip(&mut self) {
+        let last = self.run.last().copied().unwrap_or_default();
+        self.run.push(last);
+    }
+
+    /// The game time since the last split, or since the start
+    pub fn since_last_split(&self) -> Duration {
+        self.game_time.saturating_sub(self.run.last().copied().unwrap_or_default())
+    }
+
+    /// The game time of the last split minus the personal best's game time at that split
+    pub fn delta(&self) -> Option<f64> {
+        let i = self.run.len().checked_sub(1)?;
+        Some(self.run[i].as_secs_f64() - self.personal_best.get(i)?.as_secs_f64())
+    }
+
+    /// The personal best's time for the current segment minus the best segment
+    pub fn possible_time_save(&self) -> Option<f64> {
+        let i = self.run.len();
+        let pb_start = if i == 0 { Duration::ZERO } else { *self.personal_best.get(i - 1)? };
+        let pb_segment = self.personal_best.get(i)?.saturating_sub(pb_start);
+        let best = (*self.best_segments.get(i)?)?;
+        Some(pb_segment.saturating_sub(best).as_secs_f64())
+    }
+
+    fn store(&self) {
+        loop {
+            let settings_map = asr::settings::Map::load();
+            let old = settings_map.clone();
+            settings_map.insert("split_times_hash", self.splits_hash);
+            store_millis(&settings_map, "personal_best_split_times", self.personal_best.iter().copied().map(Some));
+            store_millis(&settings_map, "best_segments", self.best_segments.iter().copied());
+            if settings_map.store_if_unchanged(&old) {
+                break;
+            }
+        }
+    }
+}
+
+// --------------------------------------------------------
+
+/// Publishes the comparisons as the timer variables "delta" and "possible_time_save",
+/// only setting a variable when its text changes.
+pub struct Comparisons {
+    published: Option<(String, String)>,
+}
+
+impl Comparisons {
+    pub fn new() -> Comparisons {
+        Comparisons { published: None }
+    }
+
+    pub fn update(&mut self, split_times: &SplitTimes, enabled: bool) {
+        if !enabled {
+            self.published = None;
+            return;
+        }
+        let delta = split_times.delta().map(|d| format_seconds(d, true)).unwrap_or_else(|| "-".to_string());
+        let save = split_times.possible_time_save().map(|s| format_seconds(s, false)).unwrap_or_else(|| "-".to_string());
+        if self.published.as_ref().is_some_and(|(d, s)| d == &delta && s == &save) { return; }
+        asr::timer::set_variable("delta", &delta);
+        asr::timer::set_variable("possible_time_save", &save);
+        self.published = Some((delta, save));
+    }
+}
+
+fn splits_hash(splits: &[Split]) -> i64 {
+    let strings: Vec<String> = splits.iter().map(Split::to_string).collect();
+    stable_hash(strings.iter().map(String::as_str))
+}
+
+/// Durations stored as whole milliseconds, with -1 for None
+fn load_millis(settings_map: &asr::settings::Map, key: &str) -> Vec<Option<Duration>> {
+    let Some(list) = settings_map.get(key).and_then(|v| v.get_list()) else { return vec![]; };
+    list.iter().map(|v| {
+        let ms = v.get_i64()?;
+        Some(Duration::from_millis(u64::try_from(ms).ok()?))
+    }).collect()
+}
+
+fn store_millis(settings_map: &asr::settings::Map, key: &str, durations: impl Iterator<Item = Option<Duration>>) {
+    let list = asr::settings::List::new();
+    for d in durations {
+        list.push(d.map_or(-1, |d| d.as_millis() as i64));
+    }
+    settings_map.insert(key, &list);
+}
+
+/// Seconds as `1.23` or `1:02.30`, with a sign if `signed`
+fn format_seconds(seconds: f64, signed: bool) -> String {
+    let sign = if seconds < 0.0 { "-" } else if signed { "+" } else { "" };
+    let hundredths = (seconds.abs() * 100.0).round() as u64;
+    let (minutes, hundredths) = (hundredths / 6000, hundredths % 6000);
+    if 0 < minutes {
+        format!("{}{}:{:02}.{:02}", sign, minutes, hundredths / 100, hundredths % 100)
+    } else {
+        format!("{}{}.{:02}", sign, hundredths / 100, hundredths % 100)
+    }
+}
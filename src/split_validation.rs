@@ -0,0 +1,229 @@
+use std::fmt;
+
+use ugly_widget::radio_button::{options_description, options_str, options_value};
+
+use crate::split_catalogue::catalogue;
+use crate::splits::Split;
+
+/// The minor version of the Godmaster update, which added Godhome
+const GODMASTER_MINOR_VERSION: i32 = 4;
+
+/// A problem with the split at `index` in a list of splits
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitWarning {
+    pub index: usize,
+    pub message: String,
+}
+
+impl fmt::Display for SplitWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "split {}: {}", self.index, self.message)
+    }
+}
+
+/// Splits that count up the same PlayerData in order, along with whether they check for an exact count,
+/// so that an earlier one can never split once a later one has, instead of splitting immediately
+fn ordered_families() -> Vec<(Vec<Split>, bool)> {
+    vec![
+        (numbered("Dreamer", 1..=3), false),
+        (numbered("MrMushroom", 1..=7), false),
+        (numbered("NailUpgrade", 1..=4), false),
+        (numbered("Ore", 1..=6), false),
+        (numbered("Flame", 1..=3), false),
+        (numbered("Essence", (1..=24).map(|n| n * 100)), false),
+        (numbered("Grub", 1..=46), true),
+        (keys(&[
+            "MaskFragment1", "MaskFragment2", "MaskFragment3", "Mask1",
+            "MaskFragment5", "MaskFragment6", "MaskFragment7", "Mask2",
+            "MaskFragment9", "MaskFragment10", "MaskFragment11", "Mask3",
+            "MaskFragment13", "MaskFragment14", "MaskFragment15", "Mask4",
+        ]), true),
+        (keys(&[
+            "VesselFragment1", "VesselFragment2", "Vessel1",
+            "VesselFragment4", "VesselFragment5", "Vessel2",
+            "VesselFragment7", "VesselFragment8", "Vessel3",
+        ]), true),
+    ]
+}
+
+fn numbered(prefix: &str, ns: impl IntoIterator<Item = i32>) -> Vec<Split> {
+    ns.into_iter().filter_map(|n| options_value(&format!("{}{}", prefix, n))).collect()
+}
+
+fn keys(ks: &[&str]) -> Vec<Split> {
+    ks.iter().filter_map(|k| options_value(k)).collect()
+}
+
+/// Whether the split can only happen once in a save file, so that a duplicate of it will split immediately or never
+fn happens_once(split: &Split) -> bool {
+    matches!(split,
+        Split::Lurien | Split::Monomon | Split::Hegemol | Split::Dreamer1 | Split::Dreamer2 | Split::Dreamer3 |
+        Split::LurienDreamer | Split::MonomonDreamer | Split::HegemolDreamer | Split::VengefulSpirit | Split::ShadeSoul |
+        Split::DesolateDive | Split::DescendingDark | Split::HowlingWraiths | Split::AbyssShriek | Split::MothwingCloak |
+        Split::ShadeCloak | Split::MantisClaw | Split::MonarchWings | Split::CrystalHeart | Split::IsmasTear |
+        Split::CycloneSlash | Split::DashSlash | Split::GreatSlash | Split::DreamNail | Split::DreamGate |
+        Split::DreamNail2 | Split::CityKey | Split::LumaflyLantern | Split::SimpleKey | Split::SlyKey |
+        Split::ElegantKey | Split::LoveKey | Split::PaleLurkerKey | Split::SlySimpleKey | Split::KingsBrand |
+        Split::TramPass | Split::Ore1 | Split::Ore2 | Split::Ore3 | Split::Ore4 | Split::Ore5 | Split::Ore6 |
+        Split::NailUpgrade1 | Split::NailUpgrade2 | Split::NailUpgrade3 | Split::NailUpgrade4 | Split::MaskFragment1 |
+        Split::MaskFragment2 | Split::MaskFragment3 | Split::Mask1 | Split::MaskFragment5 | Split::MaskFragment6 |
+        Split::MaskFragment7 | Split::Mask2 | Split::MaskFragment9 | Split::MaskFragment10 | Split::MaskFragment11 |
+        Split::Mask3 | Split::MaskFragment13 | Split::MaskFragment14 | Split::MaskFragment15 | Split::Mask4 |
+        Split::VesselFragment1 | Split::VesselFragment2 | Split::Vessel1 | Split::VesselFragment4 |
+        Split::VesselFragment5 | Split::Vessel2 | Split::VesselFragment7 | Split::VesselFragment8 | Split::Vessel3 |
+        Split::NotchShrumalOgres | Split::NotchSalubra1 | Split::NotchSalubra2 | Split::NotchSalubra3 |
+        Split::NotchSalubra4 | Split::NotchFogCanyon | Split::NotchGrimm | Split::GatheringSwarm | Split::WaywardCompass |
+        Split::Grubsong | Split::StalwartShell | Split::BaldurShell | Split::FuryOfTheFallen | Split::QuickFocus |
+        Split::LifebloodHeart | Split::LifebloodCore | Split::DefendersCrest | Split::Flukenest | Split::ThornsOfAgony |
+        Split::MarkOfPride | Split::SteadyBody | Split::HeavyBlow | Split::SharpShadow | Split::SporeShroom |
+        Split::Longnail | Split::ShamanStone | Split::SoulCatcher | Split::SoulEater | Split::GlowingWomb |
+        Split::NailmastersGlory | Split::JonisBlessing | Split::ShapeOfUnn | Split::Hiveblood | Split::DreamWielder |
+        Split::Dashmaster | Split::QuickSlash | Split::SpellTwister | Split::DeepFocus | Split::GrubberflysElegy |
+        Split::Sprintmaster | Split::Dreamshield | Split::Weaversong | Split::FragileHeart | Split::UnbreakableHeart |
+        Split::FragileGreed | Split::UnbreakableGreed | Split::FragileStrength | Split::UnbreakableStrength |
+        Split::AllUnbreakables | Split::Grimmchild | Split::Grimmchild2 | Split::Grimmchild3 | Split::Grimmchild4 |
+        Split::CarefreeMelody | Split::Flame1 | Split::Flame2 | Split::Flame3 | Split::BrummFlame |
+        Split::WhiteFragmentLeft | Split::WhiteFragmentRight | Split::Kingsoul | Split::VoidHeart |
+        Split::CrossroadsStation | Split::GreenpathStation | Split::QueensStationStation | Split::StoreroomsStation |
+        Split::KingsStationStation | Split::RestingGroundsStation | Split::HiddenStationStation | Split::DeepnestStation |
+        Split::QueensGardensStation | Split::StagnestStation | Split::AllSeals | Split::SoulSanctumSeal |
+        Split::GladeIdol | Split::DungDefenderIdol | Split::Grub1 | Split::Grub2 | Split::Grub3 | Split::Grub4 |
+        Split::Grub5 | Split::Grub6 | Split::Grub7 | Split::Grub8 | Split::Grub9 | Split::Grub10 | Split::Grub11 |
+        Split::Grub12 | Split::Grub13 | Split::Grub14 | Split::Grub15 | Split::Grub16 | Split::Grub17 | Split::Grub18 |
+        Split::Grub19 | Split::Grub20 | Split::Grub21 | Split::Grub22 | Split::Grub23 | Split::Grub24 | Split::Grub25 |
+        Split::Grub26 | Split::Grub27 | Split::Grub28 | Split::Grub29 | Split::Grub30 | Split::Grub31 | Split::Grub32 |
+        Split::Grub33 | Split::Grub34 | Split::Grub35 | Split::Grub36 | Split::Grub37 | Split::Grub38 | Split::Grub39 |
+        Split::Grub40 | Split::Grub41 | Split::Grub42 | Split::Grub43 | Split::Grub44 | Split::Grub45 | Split::Grub46 |
+        Split::GrubBasinDive | Split::GrubBasinWings | Split::GrubCityBelowLoveTower | Split::GrubCityBelowSanctum |
+        Split::GrubCityGuardHouse | Split::GrubCitySanctum | Split::GrubCitySpire | Split::GrubCliffsBaldurShell |
+        Split::GrubCrossroadsAcid | Split::GrubCrossroadsGuarded | Split::GrubCrossroadsSpikes |
+        Split::GrubCrossroadsVengefly | Split::GrubCrossroadsWall | Split::GrubCrystalPeaksBottomLever |
+        Split::GrubCrystalPeaksCrown | Split::GrubCrystalPeaksCrushers | Split::GrubCrystalPeaksCrystalHeart |
+        Split::GrubCrystalPeaksMimics | Split::GrubCrystalPeaksMound | Split::GrubCrystalPeaksSpikes |
+        Split::GrubDeepnestBeastsDen | Split::GrubDeepnestDark | Split::GrubDeepnestMimics | Split::GrubDeepnestNosk |
+        Split::GrubDeepnestSpikes | Split::GrubFogCanyonArchives | Split::GrubFungalBouncy |
+        Split::GrubFungalSporeShroom | Split::GrubGreenpathCornifer | Split::GrubGreenpathHunter |
+        Split::GrubGreenpathMossKnight | Split::GrubGreenpathVesselFragment | Split::GrubHiveExternal |
+        Split::GrubHiveInternal | Split::GrubKingdomsEdgeCenter | Split::GrubKingdomsEdgeOro |
+        Split::GrubQueensGardensBelowStag | Split::GrubQueensGardensUpper | Split::GrubQueensGardensWhiteLady |
+        Split::GrubRestingGroundsCrypts | Split::GrubWaterwaysCenter | Split::GrubWaterwaysHwurmps |
+        Split::GrubWaterwaysIsma | Split::Mimic1 | Split::Mimic2 | Split::Mimic3 | Split::Mimic4 | Split::Mimic5 |
+        Split::Essence100 | Split::Essence200 | Split::Essence300 | Split::Essence400 | Split::Essence500 |
+        Split::Essence600 | Split::Essence700 | Split::Essence800 | Split::Essence900 | Split::Essence1000 |
+        Split::Essence1100 | Split::Essence1200 | Split::Essence1300 | Split::Essence1400 | Split::Essence1500 |
+        Split::Essence1600 | Split::Essence1700 | Split::Essence1800 | Split::Essence1900 | Split::Essence2000 |
+        Split::Essence2100 | Split::Essence2200 | Split::Essence2300 | Split::Essence2400 | Split::Completion100 |
+        Split::Completion106 | Split::Completion112 | Split::ElderbugFlower | Split::TroupeMasterGrimm |
+        Split::NightmareKingGrimm | Split::GreyPrince | Split::GreyPrinceEssence | Split::MenderBug |
+        Split::BroodingMawlek | Split::GruzMother | Split::SlyRescued | Split::FalseKnight | Split::FailedKnight |
+        Split::FailedChampionEssence | Split::SalubrasBlessing | Split::HollowKnightBoss | Split::RadianceBoss |
+        Split::MossKnight | Split::Zote1 | Split::Hornet1 | Split::Aluba | Split::HuntersMark | Split::NoEyes |
+        Split::NoEyesEssence | Split::MegaMossCharger | Split::ElderHu | Split::ElderHuEssence | Split::BrettaRescued |
+        Split::MantisLords | Split::Gorb | Split::GorbEssence | Split::Xero | Split::XeroEssence | Split::MetGreyMourner |
+        Split::GreyMournerSeerAscended | Split::GorgeousHusk | Split::Lemm2 | Split::TollBenchCity |
+        Split::SoulMasterEncountered | Split::SoulMasterPhase1 | Split::SoulMaster | Split::SoulTyrant |
+        Split::SoulTyrantEssence | Split::BlackKnight | Split::Collector | Split::HuskMiner | Split::CrystalGuardian1 |
+        Split::CrystalGuardian2 | Split::WaterwaysManhole | Split::DungDefender | Split::WhiteDefender |
+        Split::WhiteDefenderEssence | Split::Flukemarm | Split::TollBenchBasin | Split::BrokenVessel | Split::LostKin |
+        Split::LostKinEssence | Split::WhitePalaceOrb1 | Split::WhitePalaceOrb3 | Split::WhitePalaceOrb2 |
+        Split::PathOfPain | Split::HiveKnight | Split::GreatHopper | Split::Hornet2 | Split::Markoth |
+        Split::MarkothEssence | Split::LittleFool | Split::ColosseumBronzeUnlocked | Split::ZoteKilled |
+        Split::ColosseumBronze | Split::ColosseumSilverUnlocked | Split::ColosseumSilver | Split::ColosseumGoldUnlocked |
+        Split::GodTamer | Split::ColosseumGold | Split::UumuuEncountered | Split::Uumuu | Split::TollBenchQG |
+        Split::Marmu | Split::MarmuEssence | Split::TraitorLord | Split::Zote2 | Split::TramDeepnest | Split::Nosk |
+        Split::Galien | Split::GalienEssence | Split::GodTuner | Split::MatoOroNailBros | Split::Pantheon1 |
+        Split::SheoPaintmaster | Split::Pantheon2 | Split::SlyNailsage | Split::Pantheon3 | Split::PureVessel |
+        Split::Pantheon4 | Split::Pantheon5
+    )
+}
+
+fn is_start(split: &Split) -> bool {
+    matches!(split, Split::StartNewGame | Split::StartAnyGame)
+}
+
+/// Warnings about splits that won't work the way the list implies,
+/// with the game version as numbers for the ones that depend on the patch
+pub fn validate_splits(splits: &[Split], version: Option<&[i32]>) -> Vec<SplitWarning> {
+    let mut warnings = vec![];
+    let mut warn = |index: usize, message: String| warnings.push(SplitWarning { index, message });
+    let Some(first) = splits.first() else {
+        warn(0, "there are no splits, so the timer will never start".to_string());
+        return warnings;
+    };
+    if !is_start(first) {
+        warn(0, format!("{} is not a Start split, so the First Split auto reset is off", options_description(first)));
+    }
+    let families = ordered_families();
+    for (i, split) in splits.iter().enumerate() {
+        let description = options_description(split);
+        if 0 < i && is_start(split) {
+            warn(i, format!("{} after the first split turns the First Split auto reset off", description));
+        }
+        if split == &Split::EndingSplit && i + 1 < splits.len() {
+            warn(i, format!("{} before the last split turns the First Split auto reset off", description));
+        }
+        if happens_once(split) {
+            if let Some(j) = splits[..i].iter().position(|s| s == split) {
+                warn(i, format!("{} can only happen once, so this duplicate of split {} will split immediately or never", description, j));
+            }
+        }
+        for (family, exact) in families.iter() {
+            let Some(n) = family.iter().position(|s| s == split) else { continue; };
+            let later = splits[..i].iter().enumerate().find(|(_, s)| family.iter().position(|f| f == *s).is_some_and(|m| n < m));
+            if let Some((j, s)) = later {
+                let outcome = if *exact { "can never split" } else { "will split immediately" };
+                warn(i, format!("{} {} after {} at split {}", description, outcome, options_description(s), j));
+            }
+        }
+        let before_godmaster = version.and_then(|v| v.get(1)).is_some_and(|&m| m < GODMASTER_MINOR_VERSION);
+        if before_godmaster && catalogue().get(options_str(split)).is_some_and(|e| e.region == "Godhome") {
+            let version_strings: Vec<String> = version.unwrap_or_default().iter().map(i32::to_string).collect();
+            warn(i, format!("{} needs the Godmaster update, 1.{}, but the game is version {}", description, GODMASTER_MINOR_VERSION, version_strings.join(".")));
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indices(warnings: &[SplitWarning]) -> Vec<usize> {
+        warnings.iter().map(|w| w.index).collect()
+    }
+
+    #[test]
+    fn validate_start_and_end() {
+        assert!(validate_splits(&[Split::StartNewGame, Split::Hornet1, Split::EndingSplit], None).is_empty());
+        assert_eq!(indices(&validate_splits(&[], None)), [0]);
+        assert_eq!(indices(&validate_splits(&[Split::Hornet1, Split::EndingSplit], None)), [0]);
+        assert_eq!(indices(&validate_splits(&[Split::StartNewGame, Split::StartAnyGame, Split::EndingSplit], None)), [1]);
+        assert_eq!(indices(&validate_splits(&[Split::StartNewGame, Split::EndingSplit, Split::Hornet1], None)), [1]);
+    }
+
+    #[test]
+    fn validate_duplicates() {
+        assert_eq!(indices(&validate_splits(&[Split::StartNewGame, Split::Hornet1, Split::Hornet1], None)), [2]);
+        assert!(validate_splits(&[Split::StartNewGame, Split::OnObtainGrub, Split::OnObtainGrub, Split::BenchAny, Split::BenchAny], None).is_empty());
+    }
+
+    #[test]
+    fn validate_ordered_families() {
+        assert_eq!(indices(&validate_splits(&[Split::StartNewGame, Split::Grub2, Split::Grub1], None)), [2]);
+        assert_eq!(indices(&validate_splits(&[Split::StartNewGame, Split::Mask1, Split::MaskFragment2], None)), [2]);
+        assert!(validate_splits(&[Split::StartNewGame, Split::Grub1, Split::Grub2, Split::Dreamer1, Split::Dreamer3], None).is_empty());
+    }
+
+    #[test]
+    fn validate_menu_splits() {
+        assert!(validate_splits(&[Split::StartNewGame, Split::MenuDreamer3], None).is_empty());
+    }
+
+    #[test]
+    fn validate_godmaster_version() {
+        let splits = [Split::StartNewGame, Split::Pantheon1];
+        assert_eq!(indices(&validate_splits(&splits, Some(&[1, 3, 1, 5]))), [1]);
+        assert!(validate_splits(&splits, Some(&[1, 5, 78, 11833])).is_empty());
+        assert!(validate_splits(&splits, None).is_empty());
+    }
+}
@@ -1,74 +1,100 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
 use std::str::FromStr;
+use std::time::Duration;
 
 use asr::Process;
-use asr::settings::Gui;
+use asr::settings::gui::{add_choice, add_choice_option, set_tooltip, Widget};
 use asr::watcher::Pair;
 use serde::{Deserialize, Serialize};
-use ugly_widget::radio_button::{RadioButtonOptions, options_str};
-use ugly_widget::store::StoreWidget;
+use ugly_widget::radio_button::{RadioButtonOptions, options_description, options_str, options_value};
+use ugly_widget::args::SetHeadingLevel;
+use ugly_widget::store::{StoreWidget, Widget};
+use ugly_widget::ugly_list::ListItemValue;
+use ugly_widget::ugly_number::{UglyNumber, UglyNumberArgs};
+use ugly_widget::ugly_picker::{UglyPicker, UglyPickerArgs};
+use ugly_widget::ugly_text::{UglyText, UglyTextArgs};
 
 use super::auto_splitter_settings::Settings;
+use super::settings_migration::renamed_split;
 use super::hollow_knight_memory::*;
+use super::scene_table::{Area, MapZone, ScenePattern, is_dream_scene};
 
-#[derive(Clone, Debug, Default, Deserialize, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions, Serialize)]
+// EndingSplit is stored in saved settings by that name
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, Ord, PartialEq, PartialOrd, RadioButtonOptions, Serialize)]
 pub enum Split {
     // region: Start, End, and Menu
     /// Start New Game (Start)
     /// 
     /// Splits when starting a new save file, including Normal, Steel Soul, and Godseeker mode
+    #[category = "Start, End, and Menu"]
     StartNewGame,
     /// Start Any Game (Start)
     /// 
     /// Splits when entering a new or existing save file
+    #[category = "Start, End, and Menu"]
     StartAnyGame,
     /// Rando Wake (Event)
     /// 
     /// Splits when gaining control after waking up in Rando
+    #[category = "Start, End, and Menu"]
     RandoWake,
     /// Credits Roll (Event)
     /// 
     /// Splits on any credits rolling
     #[default]
+    #[category = "Start, End, and Menu"]
     EndingSplit,
     /// The Hollow Knight (Ending)
     /// 
     /// Splits on The Hollow Knight ending
+    #[category = "Start, End, and Menu"]
     EndingA,
     /// Sealed Siblings (Ending)
     /// 
     /// Splits on Sealed Siblings ending
+    #[category = "Start, End, and Menu"]
     EndingB,
     /// Dream No More (Ending)
     /// 
     /// Splits on Dream No More ending
+    #[category = "Start, End, and Menu"]
     EndingC,
     /// Embrace the Void (Ending)
     /// 
     /// Splits on Embrace the Void ending
+    #[category = "Start, End, and Menu"]
     EndingD,
     /// Delicate Flower (Ending)
     /// 
     /// Splits on Delicate Flower ending
+    #[category = "Start, End, and Menu"]
     EndingE,
     /// Main Menu (Menu)
     /// 
     /// Splits on the main menu
+    #[category = "Start, End, and Menu"]
     Menu,
     /// Any Bench (Bench)
     /// 
     /// Splits when sitting on a bench
+    #[category = "Start, End, and Menu"]
     BenchAny,
     /// Death (Event)
     /// 
     /// Splits when player HP is 0
+    #[category = "Start, End, and Menu"]
     PlayerDeath,
     /// Any Transition (Transition)
     /// 
     /// Splits when the knight enters a transition (only one will split per transition)
+    #[category = "Start, End, and Menu"]
     AnyTransition,
     /// Transition excluding Save State (Transition)
     /// 
     /// Splits when the knight enters a transition (excludes save states and Sly's basement)
+    #[category = "Start, End, and Menu"]
     TransitionAfterSaveState,
     // endregion: Start, End, and Menu
 
@@ -76,42 +102,55 @@ pub enum Split {
     /// Lurien the Watcher (Dreamer)
     /// 
     /// Splits when you see the mask for Lurien
+    #[category = "Dreamers"]
     Lurien,
     /// Monomon the Teacher (Dreamer)
     /// 
     /// Splits when you see the mask for Monomon
+    #[category = "Dreamers"]
     Monomon,
     /// Herrah the Beast (Dreamer)
     /// 
     /// Splits when you see the mask for Herrah
+    #[category = "Dreamers"]
     Hegemol,
     /// First Dreamer (Dreamer)
     /// 
     /// Splits when you see the mask for the first dreamer killed
+    #[category = "Dreamers"]
     Dreamer1,
     /// Second Dreamer (Dreamer)
     /// 
     /// Splits when you see the mask for the second dreamer killed
+    #[category = "Dreamers"]
     Dreamer2,
     /// Third Dreamer (Dreamer)
     /// 
     /// Splits when you see the mask for the third dreamer killed
+    #[category = "Dreamers"]
     Dreamer3,
     /// Main Menu w/ 3 Dreamers (Menu)
     /// 
     /// Splits on transition to the main menu after 3 Dreamers acquired
+    #[category = "Dreamers"]
     MenuDreamer3,
     /// Lurien (Old Dreamer Timing)
     /// 
     /// Matches the old legacy split. Splits when Lurien is registered as defeated (After killing Watcher Knight)
+    #[category = "Dreamers"]
+    #[deprecated_option]
     LurienDreamer,
     /// Monomon (Old Dreamer Timing)
     /// 
     /// Matches the old legacy split. Splits when Monomon is registered as defeated (After killing Uumuu)
+    #[category = "Dreamers"]
+    #[deprecated_option]
     MonomonDreamer,
     /// Herrah (Old Dreamer Timing)
     /// 
     /// Matches the old legacy split. Splits when Herrah is registered as defeated (In Spider Area)
+    #[category = "Dreamers"]
+    #[deprecated_option]
     HegemolDreamer,
     // endregion: Dreamers
 
@@ -119,30 +158,37 @@ pub enum Split {
     /// Mr. Mushroom 1 (Spot)
     /// 
     /// Splits when talking to Mister Mushroom in Fungal Wastes
+    #[category = "Mr Mushroom"]
     MrMushroom1,
     /// Mr. Mushroom 2 (Spot)
     /// 
     /// Splits when talking to Mister Mushroom in Kingdom's Edge
+    #[category = "Mr Mushroom"]
     MrMushroom2,
     /// Mr. Mushroom 3 (Spot)
     /// 
     /// Splits when talking to Mister Mushroom in Deepnest
+    #[category = "Mr Mushroom"]
     MrMushroom3,
     /// Mr. Mushroom 4 (Spot)
     /// 
     /// Splits when talking to Mister Mushroom in Mato's Hut
+    #[category = "Mr Mushroom"]
     MrMushroom4,
     /// Mr. Mushroom 5 (Spot)
     /// 
     /// Splits when talking to Mister Mushroom in Ancient Basin
+    #[category = "Mr Mushroom"]
     MrMushroom5,
     /// Mr. Mushroom 6 (Spot)
     /// 
     /// Splits when talking to Mister Mushroom by Overgrown Mound
+    #[category = "Mr Mushroom"]
     MrMushroom6,
     /// Mr. Mushroom 7 (Spot)
     /// 
     /// Splits when talking to Mister Mushroom in King's Pass
+    #[category = "Mr Mushroom"]
     MrMushroom7,
     // endregion: Mr Mushroom
 
@@ -150,39 +196,49 @@ pub enum Split {
     /// Vengeful Spirit (Skill)
     /// 
     /// Splits when obtaining Vengeful Spirit
+    #[category = "Spell Levels"]
     VengefulSpirit,
     /// Has Vengeful Spirit (Transition)
     /// 
     /// Splits on transition after Vengeful Spirit acquired
+    #[category = "Spell Levels"]
     TransVS,
     /// Shade Soul (Skill)
     /// 
     /// Splits when obtaining Shade Soul
+    #[category = "Spell Levels"]
     ShadeSoul,
     /// Has Shade Soul (Transition)
     /// 
     /// Splits on transition after Shade Soul acquired
+    #[category = "Spell Levels"]
     TransShadeSoul,
+    #[category = "Spell Levels"]
     MenuShadeSoul,
     /// Desolate Dive (Skill)
     /// 
     /// Splits when obtaining Desolate Dive
+    #[category = "Spell Levels"]
     DesolateDive,
     /// Descending Dark (Skill)
     /// 
     /// Splits when obtaining Descending Dark
+    #[category = "Spell Levels"]
     DescendingDark,
     /// Has Descending Dark (Transition)
     /// 
     /// Splits on transition after Descending Dark acquired
+    #[category = "Spell Levels"]
     TransDescendingDark,
     /// Howling Wraiths (Skill)
     /// 
     /// Splits when obtaining Howling Wraiths
+    #[category = "Spell Levels"]
     HowlingWraiths,
     /// Abyss Shriek (Skill)
     /// 
     /// Splits when obtaining Abyss Shriek
+    #[category = "Spell Levels"]
     AbyssShriek,
     // endregion: Spell Levels
 
@@ -190,47 +246,59 @@ pub enum Split {
     /// Mothwing Cloak (Skill)
     /// 
     /// Splits when obtaining Mothwing Cloak
+    #[category = "Movement Abilities"]
     MothwingCloak,
     /// Main Menu w/ Mothwing Cloak (Menu)
     /// 
     /// Splits on transition to the main menu after Mothwing Cloak acquired
+    #[category = "Movement Abilities"]
     MenuCloak,
     /// Shade Cloak (Skill)
     /// 
     /// Splits when obtaining Shade Cloak
+    #[category = "Movement Abilities"]
     ShadeCloak,
     /// Mantis Claw (Skill)
     /// 
     /// Splits when obtaining Mantis Claw
+    #[category = "Movement Abilities"]
     MantisClaw,
     /// Has Claw (Transition)
     /// 
     /// Splits on transition after Mantis Claw acquired
+    #[category = "Movement Abilities"]
     TransClaw,
     /// Main Menu w/ Claw (Menu)
     /// 
     /// Splits on transition to the main menu after Mantis Claw acquired
+    #[category = "Movement Abilities"]
     MenuClaw,
     /// Monarch Wings (Skill)
     /// 
     /// Splits when obtaining Monarch Wings
+    #[category = "Movement Abilities"]
     MonarchWings,
+    #[category = "Movement Abilities"]
     MenuWings,
     /// Crystal Heart (Skill)
     /// 
     /// Splits when obtaining Crystal Heart
+    #[category = "Movement Abilities"]
     CrystalHeart,
     /// Isma's Tear (Skill)
     /// 
     /// Splits when obtaining Isma's Tear
+    #[category = "Movement Abilities"]
     IsmasTear,
     /// Has Isma's Tear (Transition)
     /// 
     /// Splits on transition after Isma's Tear acquired
+    #[category = "Movement Abilities"]
     TransTear,
     /// Main Menu w/ Isma's Tear (Menu)
     /// 
     /// Splits on transition to the main menu after Isma's Tear acquired
+    #[category = "Movement Abilities"]
     MenuIsmasTear,
     // endregion: Movement Abilities
 
@@ -238,14 +306,17 @@ pub enum Split {
     /// Cyclone Slash (Skill)
     /// 
     /// Splits when obtaining Cyclone Slash
+    #[category = "Nail Arts"]
     CycloneSlash,
     /// Dash Slash (Skill)
     /// 
     /// Splits when obtaining Dash Slash
+    #[category = "Nail Arts"]
     DashSlash,
     /// Great Slash (Skill)
     /// 
     /// Splits when obtaining Great Slash
+    #[category = "Nail Arts"]
     GreatSlash,
     // endregion: Nail Arts
 
@@ -253,22 +324,27 @@ pub enum Split {
     /// Dream Nail (Skill)
     /// 
     /// Splits when obtaining Dream Nail
+    #[category = "Dream Nail Levels"]
     DreamNail,
     /// Main Menu w/ Dream Nail (Menu)
     /// 
     /// Splits on transition to the main menu after Dream Nail acquired
+    #[category = "Dream Nail Levels"]
     MenuDreamNail,
     /// Dream Gate (Skill)
     /// 
     /// Splits when obtaining Dream Gate
+    #[category = "Dream Nail Levels"]
     DreamGate,
     /// Main Menu w/ Dream Gate (Menu)
     /// 
     /// Splits on transition to the main menu after Dream Gate acquired
+    #[category = "Dream Nail Levels"]
     MenuDreamGate,
     /// Dream Nail - Awoken (Skill)
     /// 
     /// Splits when Awkening the Dream Nail
+    #[category = "Dream Nail Levels"]
     DreamNail2,
     // endregion: Dream Nail Levels
 
@@ -276,50 +352,62 @@ pub enum Split {
     /// City Crest (Item)
     /// 
     /// Splits when obtaining the City Crest
+    #[category = "Keys"]
     CityKey,
     /// Lumafly Lantern (Item)
     /// 
     /// Splits when obtaining the Lumafly Lantern
+    #[category = "Keys"]
     LumaflyLantern,
     /// Shop Lumafly Lantern (Transition)
     /// 
     /// Splits on transition after Lantern has been acquired
+    #[category = "Keys"]
     LumaflyLanternTransition,
     /// Simple Key - First (Item)
     /// 
     /// Splits when obtaining the first Simple Key
+    #[category = "Keys"]
     SimpleKey,
     /// Simple Key (Obtain)
     /// 
     /// Splits when obtaining a Simple Key
+    #[category = "Keys"]
     OnObtainSimpleKey,
     /// Shopkeeper's Key (Item)
     /// 
     /// Splits when obtaining the Shopkeeper's Key
+    #[category = "Keys"]
     SlyKey,
     /// Elegant Key (Item)
     /// 
     /// Splits when obtaining the Elegant Key
+    #[category = "Keys"]
     ElegantKey,
     /// Love Key (Item)
     /// 
     /// Splits when obtaining the Love Key
+    #[category = "Keys"]
     LoveKey,
     /// Pale Lurker Key (Item)
     /// 
     /// Splits when obtaining the Simple Key from the Pale Lurker
+    #[category = "Keys"]
     PaleLurkerKey,
     /// Sly Simple Key (Item)
     /// 
     /// Splits when buying the simple key from Sly
+    #[category = "Keys"]
     SlySimpleKey,
     /// King's Brand (Item)
     /// 
     /// Splits when obtaining the King's Brand
+    #[category = "Keys"]
     KingsBrand,
     /// Tram Pass (Item)
     /// 
     /// Splits when obtaining the Tram Pass
+    #[category = "Keys"]
     TramPass,
     // endregion: Keys
 
@@ -327,46 +415,57 @@ pub enum Split {
     /// Pale Ore (Obtain)
     /// 
     /// Splits when obtaining a Pale Ore
+    #[category = "Nail and Pale Ore"]
     OnObtainPaleOre,
     /// Pale Ore 1 (Ore)
     /// 
     /// Splits after obtaining the first pale ore.
+    #[category = "Nail and Pale Ore"]
     Ore1,
     /// Pale Ore 2 (Ore)
     /// 
     /// Splits after obtaining the second pale ore.
+    #[category = "Nail and Pale Ore"]
     Ore2,
     /// Pale Ore 3 (Ore)
     /// 
     /// Splits after obtaining the third pale ore.
+    #[category = "Nail and Pale Ore"]
     Ore3,
     /// Pale Ore 4 (Ore)
     /// 
     /// Splits after obtaining the fourth pale ore.
+    #[category = "Nail and Pale Ore"]
     Ore4,
     /// Pale Ore 5 (Ore)
     /// 
     /// Splits after obtaining the fifth pale ore.
+    #[category = "Nail and Pale Ore"]
     Ore5,
     /// Pale Ore 6 (Ore)
     /// 
     /// Splits after obtaining the sixth pale ore.
+    #[category = "Nail and Pale Ore"]
     Ore6,
     /// Nail 1 (Upgrade)
     /// 
     /// Splits upon upgrading to the Sharpened Nail
+    #[category = "Nail and Pale Ore"]
     NailUpgrade1,
     /// Nail 2 (Upgrade)
     /// 
     /// Splits upon upgrading to the Channeled Nail
+    #[category = "Nail and Pale Ore"]
     NailUpgrade2,
     /// Nail 3 (Upgrade)
     /// 
     /// Splits upon upgrading to the Coiled Nail
+    #[category = "Nail and Pale Ore"]
     NailUpgrade3,
     /// Nail 4 (Upgrade)
     /// 
     /// Splits upon upgrading to the Pure Nail
+    #[category = "Nail and Pale Ore"]
     NailUpgrade4,
     // endregion: Nail and Pale Ore
 
@@ -374,118 +473,147 @@ pub enum Split {
     /// Mask Shard (Obtain)
     /// 
     /// Splits when obtaining a Mask Shard or upgrade for complete Mask
+    #[category = "Masks and Mask Shards"]
     OnObtainMaskShard,
     /// Mask Shard 1 (Fragment)
     /// 
     /// Splits when getting 1st Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment1,
     /// Mask Shard 2 (Fragment)
     /// 
     /// Splits when getting 2nd Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment2,
     /// Mask Shard 3 (Fragment)
     /// 
     /// Splits when getting 3rd Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment3,
     /// Mask Upgrade 4 (Upgrade)
     /// 
     /// Splits when getting 1 extra Mask (6 base HP)
+    #[category = "Masks and Mask Shards"]
     Mask1,
     /// Mask Shard 5 (Fragment)
     /// 
     /// Splits when getting 5th Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment5,
     /// Mask Shard 6 (Fragment)
     /// 
     /// Splits when getting 6th Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment6,
     /// Mask Shard 7 (Fragment)
     /// 
     /// Splits when getting 7th Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment7,
     /// Mask Upgrade 8 (Upgrade)
     /// 
     /// Splits when getting 2 extra Masks (7 base HP)
+    #[category = "Masks and Mask Shards"]
     Mask2,
     /// Mask Shard 9 (Fragment)
     /// 
     /// Splits when getting 9th Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment9,
     /// Mask Shard 10 (Fragment)
     /// 
     /// Splits when getting 10th Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment10,
     /// Mask Shard 11 (Fragment)
     /// 
     /// Splits when getting 11th Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment11,
     /// Mask Upgrade 12 (Upgrade)
     /// 
     /// Splits when getting 3 extra Masks (8 base HP)
+    #[category = "Masks and Mask Shards"]
     Mask3,
     /// Mask Shard 13 (Fragment)
     /// 
     /// Splits when getting 13th Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment13,
     /// Mask Shard 14 (Fragment)
     /// 
     /// Splits when getting 14th Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment14,
     /// Mask Shard 15 (Fragment)
     /// 
     /// Splits when getting 15th Mask Shard
+    #[category = "Masks and Mask Shards"]
     MaskFragment15,
     /// Mask Upgrade 16 (Upgrade)
     /// 
     /// Splits when getting 4 extra Masks (9 base HP)
+    #[category = "Masks and Mask Shards"]
     Mask4,
     /// Brooding Mawlek Mask Shard (Obtain)
     /// 
     /// Splits when getting the Mask Shard from Brooding Mawlek
+    #[category = "Masks and Mask Shards"]
     MaskShardMawlek,
     /// Grub Reward Mask Shard (Obtain)
     /// 
     /// Splits when getting the Mask Shard given by Grubfather
+    #[category = "Masks and Mask Shards"]
     MaskShardGrubfather,
     /// Goam Mask Shard (Obtain)
     /// 
     /// Splits when getting the Goam Mask Shard in Forgotten Crossroads
+    #[category = "Masks and Mask Shards"]
     MaskShardGoam,
     /// Queen's Station Mask Shard (Obtain)
     /// 
     /// Splits when getting the Mask Shard in Queen's Station
+    #[category = "Masks and Mask Shards"]
     MaskShardQueensStation,
     /// Bretta Mask Shard (Obtain)
     /// 
     /// Splits when getting the Mask Shard in Bretta's hut in Dirtmouth
+    #[category = "Masks and Mask Shards"]
     MaskShardBretta,
     /// Stone Sanctuary Mask Shard (Obtain)
     /// 
     /// Splits when getting the Mask Shard in Stone Sanctuary
+    #[category = "Masks and Mask Shards"]
     MaskShardStoneSanctuary,
     /// Waterways Mask Shard (Obtain)
     /// 
     /// Splits when getting the Mask Shard in Royal Wayerways
+    #[category = "Masks and Mask Shards"]
     MaskShardWaterways,
     /// Fungal Core Mask Shard (Obtain)
     /// 
     /// Splits when getting the Mask Shard below Fungal Core
+    #[category = "Masks and Mask Shards"]
     MaskShardFungalCore,
     /// Enraged Guardian Mask Shard (Obtain)
     /// 
     /// Splits when getting the Mask Shard from Enraged Guardian
+    #[category = "Masks and Mask Shards"]
     MaskShardEnragedGuardian,
     /// Hive Mask Shard (Obtain)
     /// 
     /// Splits when getting the Mask Shard in the Hive
+    #[category = "Masks and Mask Shards"]
     MaskShardHive,
     /// Seer Mask Shard (Obtain)
     /// 
     /// Splits when getting the Mask Shard from Seer
+    #[category = "Masks and Mask Shards"]
     MaskShardSeer,
     /// Grey Mourner Mask Shard (Obtain)
     /// 
     /// Splits when getting the Mask Shard from Grey Mourner
+    #[category = "Masks and Mask Shards"]
     MaskShardFlower,
     // endregion: Masks and Mask Shards
 
@@ -493,70 +621,87 @@ pub enum Split {
     /// Vessel Fragment (Obtain)
     /// 
     /// Splits when obtaining a Vessel Fragment or on upgrade for full Soul Vessel
+    #[category = "Vessels and Vessel Fragments"]
     OnObtainVesselFragment,
     /// Vessel Fragment 1 (Fragment)
     /// 
     /// Splits when getting 1st Soul Vessel Fragment
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragment1,
     /// Vessel Fragment 2 (Fragment)
     /// 
     /// Splits when getting 2nd Soul Vessel Fragment
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragment2,
     /// Soul Vessel 1 (Upgrade)
     /// 
     /// Splits when upgrading to 1 Soul Vessel (3 Soul Vessel Fragments)
+    #[category = "Vessels and Vessel Fragments"]
     Vessel1,
     /// Vessel Fragment 4 (Fragment)
     /// 
     /// Splits when getting 4th Soul Vessel Fragment
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragment4,
     /// Vessel Fragment 5 (Fragment)
     /// 
     /// Splits when getting 5th Soul Vessel Fragment
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragment5,
     /// Soul Vessel 2 (Upgrade)
     /// 
     /// Splits when upgrading to 2 Soul Vessels (6 Soul Vessel Fragments)
+    #[category = "Vessels and Vessel Fragments"]
     Vessel2,
     /// Vessel Fragment 7 (Fragment)
     /// 
     /// Splits when getting 7th Soul Vessel Fragment
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragment7,
     /// Vessel Fragment 8 (Fragment)
     /// 
     /// Splits when getting 8th Soul Vessel Fragment
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragment8,
     /// Soul Vessel 3 (Upgrade)
     /// 
     /// Splits when upgrading to 3 Soul Vessels (9 Soul Vessel Fragments)
+    #[category = "Vessels and Vessel Fragments"]
     Vessel3,
     /// Greenpath Vessel Fragment (Obtain)
     /// 
     /// Splits when getting Vessel Fragment in Greenpath
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragGreenpath,
     /// Crossroads Vessel Fragment (Obtain)
     /// 
     /// Splits when getting the Vessel Fragment in Forgotten Crossroads
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragCrossroadsLift,
     /// King's Station Vessel Fragment (Obtain)
     /// 
     /// Splits when getting the Vessel Fragment after the arena above King's Station
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragKingsStation,
     /// Deepnest Vessel Fragment (Obtain)
     /// 
     /// Splits when getting the Vessel Fragment in Deepnest
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragGarpedes,
     /// Stag Nest Vessel Fragment (Obtain)
     /// 
     /// Splits when getting the Vessel Fragment in Stag Nest
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragStagNest,
     /// Seer Vessel Fragment (Obtain)
     /// 
     /// Splits when getting the Vessel Fragment from Seer
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragSeer,
     /// Basin Fountain Vessel Fragment (Obtain)
     /// 
     /// Splits when getting the Vessel Fragment from the fountain in Ancient Basin
+    #[category = "Vessels and Vessel Fragments"]
     VesselFragFountain,
     // endregion: Vessels and Vessel Fragments
 
@@ -564,34 +709,42 @@ pub enum Split {
     /// Shrumal Ogres (Charm Notch)
     /// 
     /// Splits when obtaining the charm notch after defeating the Shrumal Ogres
+    #[category = "Charm Notches"]
     NotchShrumalOgres,
     /// Salubra 1 (Charm Notch)
     /// 
     /// Splits when obtaining the first charm notch from Salubra
+    #[category = "Charm Notches"]
     NotchSalubra1,
     /// Salubra 2 (Charm Notch)
     /// 
     /// Splits when obtaining the second charm notch from Salubra
+    #[category = "Charm Notches"]
     NotchSalubra2,
     /// Salubra 3 (Charm Notch)
     /// 
     /// Splits when obtaining the third charm notch from Salubra
+    #[category = "Charm Notches"]
     NotchSalubra3,
     /// Salubra 4 (Charm Notch)
     /// 
     /// Splits when obtaining the fourth charm notch from Salubra
+    #[category = "Charm Notches"]
     NotchSalubra4,
     /// Fog Canyon (Charm Notch)
     /// 
     /// Splits when obtaining the charm notch in Fog Canyon
+    #[category = "Charm Notches"]
     NotchFogCanyon,
     /// Grimm (Charm Notch)
     /// 
     /// Splits when obtaining the charm notch after Grimm
+    #[category = "Charm Notches"]
     NotchGrimm,
     /// Charm Notch (Obtain)
     /// 
     /// Splits when obtaining a new Charm Slot
+    #[category = "Charm Notches"]
     OnObtainCharmNotch,
     // endregion: Charm Notches
 
@@ -599,241 +752,300 @@ pub enum Split {
     /// Gathering Swarm (Charm)
     /// 
     /// Splits when obtaining the Gathering Swarm charm
+    #[category = "Charms"]
     GatheringSwarm,
     /// Wayward Compass (Charm)
     /// 
     /// Splits when obtaining Wayward Compass charm
+    #[category = "Charms"]
     WaywardCompass,
     /// Grubsong (Charm)
     /// 
     /// Splits when obtaining the Grubsong charm
+    #[category = "Charms"]
     Grubsong,
     /// Stalwart Shell (Charm)
     /// 
     /// Splits when obtaining Stalwart Shell charm
+    #[category = "Charms"]
     StalwartShell,
     /// Baldur Shell (Charm)
     /// 
     /// Splits when obtaining the Baldur Shell charm
+    #[category = "Charms"]
     BaldurShell,
     /// Fury of the Fallen (Charm)
     /// 
     /// Splits when obtaining the Fury of the Fallen charm
+    #[category = "Charms"]
     FuryOfTheFallen,
     /// Quick Focus (Charm)
     /// 
     /// Splits when obtaining the Quick Focus charm
+    #[category = "Charms"]
     QuickFocus,
     /// Lifeblood Heart (Charm)
     /// 
     /// Splits when obtaining the Lifeblood Heart charm
+    #[category = "Charms"]
     LifebloodHeart,
     /// Lifeblood Core (Charm)
     /// 
     /// Splits when obtaining the Lifeblood Core charm
+    #[category = "Charms"]
     LifebloodCore,
     /// Defenders Crest (Charm)
     /// 
     /// Splits when obtaining the Defenders Crest charm
+    #[category = "Charms"]
     DefendersCrest,
     /// Flukenest (Charm)
     /// 
     /// Splits when obtaining the Flukenest charm
+    #[category = "Charms"]
     Flukenest,
     /// Thorns of Agony (Charm)
     /// 
     /// Splits when obtaining Thorns of Agony charm
+    #[category = "Charms"]
     ThornsOfAgony,
     /// Mark of Pride (Charm)
     /// 
     /// Splits when obtaining the Mark of Pride charm
+    #[category = "Charms"]
     MarkOfPride,
     /// Steady Body (Charm)
     /// 
     /// Splits when obtaining the Steady Body charm
+    #[category = "Charms"]
     SteadyBody,
     /// Heavy Blow (Charm)
     /// 
     /// Splits when obtaining the Heavy Blow charm
+    #[category = "Charms"]
     HeavyBlow,
     /// Sharp Shadow (Charm)
     /// 
     /// Splits when obtaining Sharp Shadow charm
+    #[category = "Charms"]
     SharpShadow,
     /// Spore Shroom (Charm)
     /// 
     /// Splits when obtaining the Spore Shroom charm
+    #[category = "Charms"]
     SporeShroom,
     /// Longnail (Charm)
     /// 
     /// Splits when obtaining the Longnail charm
+    #[category = "Charms"]
     Longnail,
     /// Shaman Stone (Charm)
     /// 
     /// Splits when obtaining Shaman Stone charm
+    #[category = "Charms"]
     ShamanStone,
     /// Soul Catcher (Charm)
     /// 
     /// Splits when obtaining the Soul Catcher charm
+    #[category = "Charms"]
     SoulCatcher,
     /// Soul Eater (Charm)
     /// 
     /// Splits when obtaining the Soul Eater charm
+    #[category = "Charms"]
     SoulEater,
     /// Glowing Womb (Charm)
     /// 
     /// Splits when obtaining the Glowing Womb charm
+    #[category = "Charms"]
     GlowingWomb,
     /// Nailmaster's Glory (Charm)
     /// 
     /// Splits when obtaining the Nailmaster's Glory charm
+    #[category = "Charms"]
     NailmastersGlory,
     /// Joni's Blessing (Charm)
     /// 
     /// Splits when obtaining the Joni's Blessing charm
+    #[category = "Charms"]
     JonisBlessing,
     /// Shape of Unn (Charm)
     /// 
     /// Splits when obtaining Shape of Unn charm
+    #[category = "Charms"]
     ShapeOfUnn,
     /// Hiveblood (Charm)
     /// 
     /// Splits when obtaining the Hiveblood charm
+    #[category = "Charms"]
     Hiveblood,
     /// Dream Wielder (Charm)
     /// 
     /// Splits when obtaining the Dream Wielder charm
+    #[category = "Charms"]
     DreamWielder,
     /// Dashmaster (Charm)
     /// 
     /// Splits when obtaining the Dashmaster charm
+    #[category = "Charms"]
     Dashmaster,
     /// Main Menu w/ Dashmaster (Menu)
     /// 
     /// Splits on transition to the main menu after Dashmaster acquired
+    #[category = "Charms"]
     MenuDashmaster,
     /// Quick Slash (Charm)
     /// 
     /// Splits when obtaining the Quick Slash charm
+    #[category = "Charms"]
     QuickSlash,
     /// Spell Twister (Charm)
     /// 
     /// Splits when obtaining the Spell Twister charm
+    #[category = "Charms"]
     SpellTwister,
     /// Deep Focus (Charm)
     /// 
     /// Splits when obtaining the Deep Focus charm
+    #[category = "Charms"]
     DeepFocus,
     /// Grubberfly's Elegy (Charm)
     /// 
     /// Splits when obtaining the Grubberfly's Elegy charm
+    #[category = "Charms"]
     GrubberflysElegy,
     /// Sprintmaster (Charm)
     /// 
     /// Splits when obtaining the Sprintmaster charm
+    #[category = "Charms"]
     Sprintmaster,
     /// Dreamshield (Charm)
     /// 
     /// Splits when obtaining the Dreamshield charm
+    #[category = "Charms"]
     Dreamshield,
     /// Weaversong (Charm)
     /// 
     /// Splits when obtaining the Weaversong charm
+    #[category = "Charms"]
     Weaversong,
     // Fragile / Unbreakable Charms
     /// Fragile Heart (Charm)
     /// 
     /// Splits when obtaining the Fragile Heart charm
+    #[category = "Charms"]
     FragileHeart,
     /// Unbreakable Heart (Charm)
     /// 
     /// Splits when obtaining the Unbreakable Heart charm
+    #[category = "Charms"]
     UnbreakableHeart,
     /// Fragile Greed (Charm)
     /// 
     /// Splits when obtaining the Fragile Greed charm
+    #[category = "Charms"]
     FragileGreed,
     /// Unbreakable Greed (Charm)
     /// 
     /// Splits when obtaining the Unbreakable Greed charm
+    #[category = "Charms"]
     UnbreakableGreed,
     /// Fragile Strength (Charm)
     /// 
     /// Splits when obtaining the Fragile Strength charm
+    #[category = "Charms"]
     FragileStrength,
     /// Unbreakable Strength (Charm)
     /// 
     /// Splits when obtaining the Unbreakable Strength charm
+    #[category = "Charms"]
     UnbreakableStrength,
     /// All Breakables (Event)
     /// 
     /// Splits when all 3 fragile charms are broken
+    #[category = "Charms"]
     AllBreakables,
     /// All Unbreakables (Charm)
     /// 
     /// Splits when all 3 unbreakable charms are obtained
+    #[category = "Charms"]
     AllUnbreakables,
     // Grimmchild / Carefree Melody
     /// Grimmchild (Charm)
     /// 
     /// Splits when obtaining the Grimmchild charm
+    #[category = "Charms"]
     Grimmchild,
     /// Grimmchild Lvl 2 (Charm)
     /// 
     /// Splits when upgrading Grimmchild to level 2
+    #[category = "Charms"]
     Grimmchild2,
     /// Grimmchild Lvl 3 (Charm)
     /// 
     /// Splits when upgrading Grimmchild to level 3
+    #[category = "Charms"]
     Grimmchild3,
     /// Grimmchild Lvl 4 (Charm)
     /// 
     /// Splits when upgrading Grimmchild to level 4
+    #[category = "Charms"]
     Grimmchild4,
     /// Carefree Melody (Charm)
     /// 
     /// Splits when obtaining the Carefree Melody charm
+    #[category = "Charms"]
     CarefreeMelody,
     /// Grimm Flame 1 (Flame)
     /// 
     /// Splits after obtaining the first flame.
+    #[category = "Charms"]
     Flame1,
     /// Grimm Flame 2 (Flame)
     /// 
     /// Splits after obtaining the second flame.
+    #[category = "Charms"]
     Flame2,
     /// Grimm Flame 3 (Flame)
     /// 
     /// Splits after obtaining the third flame.
+    #[category = "Charms"]
     Flame3,
     /// Brumm Flame (NPC)
     /// 
     /// Splits when collecting Brumm's flame in Deepnest
+    #[category = "Charms"]
     BrummFlame,
     // Kingsoul / VoidHeart
     /// White Fragment - Queen's (Charm)
     /// 
     /// Splits on picking up the left White Fragment from the White Lady
+    #[category = "Charms"]
     WhiteFragmentLeft,
     /// White Fragment - King's (Charm)
     /// 
     /// Splits on picking up the right White Fragment from the Pale King
+    #[category = "Charms"]
     WhiteFragmentRight,
     /// White Fragment (Obtain)
     /// 
     /// Splits when obtaining any White Fragment, or Void Heart
+    #[category = "Charms"]
     OnObtainWhiteFragment,
     /// Kingsoul (Charm)
     /// 
     /// Splits when obtaining the completed Kingsoul charm
+    #[category = "Charms"]
     Kingsoul,
     /// Void Heart (Charm)
     /// 
     /// Splits when changing the Kingsoul to the Void Heart charm
+    #[category = "Charms"]
     VoidHeart,
     /// Main Menu w/ Void Heart (Menu)
     /// 
     /// Splits on transition to the main menu after Void Heart acquired
+    #[category = "Charms"]
     MenuVoidHeart,
     // endregion: Charms
 
@@ -841,50 +1053,62 @@ pub enum Split {
     /// Riding Stag (Event)
     /// 
     /// Splits while riding the stag
+    #[category = "Stags"]
     RidingStag,
     /// Stag Position Updated (Event)
     /// 
     /// Splits when the stag is called
+    #[category = "Stags"]
     StagMoved,
     /// Forgotten Crossroads (Stag Station)
     /// 
     /// Splits when opening the Forgotten Crossroads Stag Station
+    #[category = "Stags"]
     CrossroadsStation,
     /// Greenpath (Stag Station)
     /// 
     /// Splits when obtaining Greenpath Stag Station
+    #[category = "Stags"]
     GreenpathStation,
     /// Queen's Station (Stag Station)
     /// 
     /// Splits when obtaining Queen's Station Stag Station
+    #[category = "Stags"]
     QueensStationStation,
     /// City Storerooms (Stag Station)
     /// 
     /// Splits when obtaining City Storerooms Stag Station
+    #[category = "Stags"]
     StoreroomsStation,
     /// King's Station (Stag Station)
     /// 
     /// Splits when obtaining King's Station Stag Station
+    #[category = "Stags"]
     KingsStationStation,
     /// Resting Grounds (Stag Station)
     /// 
     /// Splits when obtaining Resting Grounds Stag Station
+    #[category = "Stags"]
     RestingGroundsStation,
     /// Hidden Station (Stag Station)
     /// 
     /// Splits when obtaining to Hidden Station Stag Station
+    #[category = "Stags"]
     HiddenStationStation,
     /// Distant Village (Stag Station)
     /// 
     /// Splits when obtaining Distant Village Stag Station
+    #[category = "Stags"]
     DeepnestStation,
     /// Queen's Gardens (Stag Station)
     /// 
     /// Splits when obtaining Queen's Gardens Stag Station
+    #[category = "Stags"]
     QueensGardensStation,
     /// Stagnest (Stag Station)
     /// 
     /// Splits when traveling to Stagnest (Requires Ordered Splits)
+    #[category = "Stags"]
     StagnestStation,
     // endregion: Stags
 
@@ -892,42 +1116,52 @@ pub enum Split {
     /// Wanderer's Journal (Obtain)
     /// 
     /// Splits when obtaining a Wanderer's Journal
+    #[category = "Relics"]
     OnObtainWanderersJournal,
     /// All Seals (Item)
     /// 
     /// Splits when 17 Hallownest Seals have been collected
+    #[category = "Relics"]
     AllSeals,
     /// Hallownest Seal (Obtain)
     /// 
     /// Splits when obtaining a Hallownest Seal
+    #[category = "Relics"]
     OnObtainHallownestSeal,
     /// Soul Sanctum Hallownest Seal (Relic)
     /// 
     /// Splits when the Hallownest Seal in Soul Sanctum is collected
+    #[category = "Relics"]
     SoulSanctumSeal,
     /// King's Idol (Obtain)
     /// 
     /// Splits when obtaining a King's Idol
+    #[category = "Relics"]
     OnObtainKingsIdol,
     /// Glade Idol (Item)
     /// 
     /// Splits when picking up the King's Idol in the Spirits' Glade
+    #[category = "Relics"]
     GladeIdol,
     /// Dung Defender Idol (Item)
     /// 
     /// Splits when picking up Dung Defender idol as the first idol
+    #[category = "Relics"]
     DungDefenderIdol,
     /// Arcane Egg 8 (Obtain)
     /// 
     /// Splits when obtaining 8 Arcane Eggs
+    #[category = "Relics"]
     ArcaneEgg8,
     /// Arcane Egg (Obtain)
     /// 
     /// Splits when obtaining an Arcane Egg
+    #[category = "Relics"]
     OnObtainArcaneEgg,
     /// Rancid Egg (Obtain)
     /// 
     /// Splits when obtaining a Rancid Egg
+    #[category = "Relics"]
     OnObtainRancidEgg,
     // endregion: Relics
 
@@ -935,382 +1169,477 @@ pub enum Split {
     /// Rescued Grub 1 (Grub)
     /// 
     /// Splits when rescuing grub #1
+    #[category = "Grubs and Mimics"]
     Grub1,
     /// Rescued Grub 2 (Grub)
     /// 
     /// Splits when rescuing grub #2
+    #[category = "Grubs and Mimics"]
     Grub2,
     /// Rescued Grub 3 (Grub)
     /// 
     /// Splits when rescuing grub #3
+    #[category = "Grubs and Mimics"]
     Grub3,
     /// Rescued Grub 4 (Grub)
     /// 
     /// Splits when rescuing grub #4
+    #[category = "Grubs and Mimics"]
     Grub4,
     /// Rescued Grub 5 (Grub)
     /// 
     /// Splits when rescuing grub #5
+    #[category = "Grubs and Mimics"]
     Grub5,
     /// Rescued Grub 6 (Grub)
     /// 
     /// Splits when rescuing grub #6
+    #[category = "Grubs and Mimics"]
     Grub6,
     /// Rescued Grub 7 (Grub)
     /// 
     /// Splits when rescuing grub #7
+    #[category = "Grubs and Mimics"]
     Grub7,
     /// Rescued Grub 8 (Grub)
     /// 
     /// Splits when rescuing grub #8
+    #[category = "Grubs and Mimics"]
     Grub8,
     /// Rescued Grub 9 (Grub)
     /// 
     /// Splits when rescuing grub #9
+    #[category = "Grubs and Mimics"]
     Grub9,
     /// Rescued Grub 10 (Grub)
     /// 
     /// Splits when rescuing grub #10
+    #[category = "Grubs and Mimics"]
     Grub10,
     /// Rescued Grub 11 (Grub)
     /// 
     /// Splits when rescuing grub #11
+    #[category = "Grubs and Mimics"]
     Grub11,
     /// Rescued Grub 12 (Grub)
     /// 
     /// Splits when rescuing grub #12
+    #[category = "Grubs and Mimics"]
     Grub12,
     /// Rescued Grub 13 (Grub)
     /// 
     /// Splits when rescuing grub #13
+    #[category = "Grubs and Mimics"]
     Grub13,
     /// Rescued Grub 14 (Grub)
     /// 
     /// Splits when rescuing grub #14
+    #[category = "Grubs and Mimics"]
     Grub14,
     /// Rescued Grub 15 (Grub)
     /// 
     /// Splits when rescuing grub #15
+    #[category = "Grubs and Mimics"]
     Grub15,
     /// Rescued Grub 16 (Grub)
     /// 
     /// Splits when rescuing grub #16
+    #[category = "Grubs and Mimics"]
     Grub16,
     /// Rescued Grub 17 (Grub)
     /// 
     /// Splits when rescuing grub #17
+    #[category = "Grubs and Mimics"]
     Grub17,
     /// Rescued Grub 18 (Grub)
     /// 
     /// Splits when rescuing grub #18
+    #[category = "Grubs and Mimics"]
     Grub18,
     /// Rescued Grub 19 (Grub)
     /// 
     /// Splits when rescuing grub #19
+    #[category = "Grubs and Mimics"]
     Grub19,
     /// Rescued Grub 20 (Grub)
     /// 
     /// Splits when rescuing grub #20
+    #[category = "Grubs and Mimics"]
     Grub20,
     /// Rescued Grub 21 (Grub)
     /// 
     /// Splits when rescuing grub #21
+    #[category = "Grubs and Mimics"]
     Grub21,
     /// Rescued Grub 22 (Grub)
     /// 
     /// Splits when rescuing grub #22
+    #[category = "Grubs and Mimics"]
     Grub22,
     /// Rescued Grub 23 (Grub)
     /// 
     /// Splits when rescuing grub #23
+    #[category = "Grubs and Mimics"]
     Grub23,
     /// Rescued Grub 24 (Grub)
     /// 
     /// Splits when rescuing grub #24
+    #[category = "Grubs and Mimics"]
     Grub24,
     /// Rescued Grub 25 (Grub)
     /// 
     /// Splits when rescuing grub #25
+    #[category = "Grubs and Mimics"]
     Grub25,
     /// Rescued Grub 26 (Grub)
     /// 
     /// Splits when rescuing grub #26
+    #[category = "Grubs and Mimics"]
     Grub26,
     /// Rescued Grub 27 (Grub)
     /// 
     /// Splits when rescuing grub #27
+    #[category = "Grubs and Mimics"]
     Grub27,
     /// Rescued Grub 28 (Grub)
     /// 
     /// Splits when rescuing grub #28
+    #[category = "Grubs and Mimics"]
     Grub28,
     /// Rescued Grub 29 (Grub)
     /// 
     /// Splits when rescuing grub #29
+    #[category = "Grubs and Mimics"]
     Grub29,
     /// Rescued Grub 30 (Grub)
     /// 
     /// Splits when rescuing grub #30
+    #[category = "Grubs and Mimics"]
     Grub30,
     /// Rescued Grub 31 (Grub)
     /// 
     /// Splits when rescuing grub #31
+    #[category = "Grubs and Mimics"]
     Grub31,
     /// Rescued Grub 32 (Grub)
     /// 
     /// Splits when rescuing grub #32
+    #[category = "Grubs and Mimics"]
     Grub32,
     /// Rescued Grub 33 (Grub)
     /// 
     /// Splits when rescuing grub #33
+    #[category = "Grubs and Mimics"]
     Grub33,
     /// Rescued Grub 34 (Grub)
     /// 
     /// Splits when rescuing grub #34
+    #[category = "Grubs and Mimics"]
     Grub34,
     /// Rescued Grub 35 (Grub)
     /// 
     /// Splits when rescuing grub #35
+    #[category = "Grubs and Mimics"]
     Grub35,
     /// Rescued Grub 36 (Grub)
     /// 
     /// Splits when rescuing grub #36
+    #[category = "Grubs and Mimics"]
     Grub36,
     /// Rescued Grub 37 (Grub)
     /// 
     /// Splits when rescuing grub #37
+    #[category = "Grubs and Mimics"]
     Grub37,
     /// Rescued Grub 38 (Grub)
     /// 
     /// Splits when rescuing grub #38
+    #[category = "Grubs and Mimics"]
     Grub38,
     /// Rescued Grub 39 (Grub)
     /// 
     /// Splits when rescuing grub #39
+    #[category = "Grubs and Mimics"]
     Grub39,
     /// Rescued Grub 40 (Grub)
     /// 
     /// Splits when rescuing grub #40
+    #[category = "Grubs and Mimics"]
     Grub40,
     /// Rescued Grub 41 (Grub)
     /// 
     /// Splits when rescuing grub #41
+    #[category = "Grubs and Mimics"]
     Grub41,
     /// Rescued Grub 42 (Grub)
     /// 
     /// Splits when rescuing grub #42
+    #[category = "Grubs and Mimics"]
     Grub42,
     /// Rescued Grub 43 (Grub)
     /// 
     /// Splits when rescuing grub #43
+    #[category = "Grubs and Mimics"]
     Grub43,
     /// Rescued Grub 44 (Grub)
     /// 
     /// Splits when rescuing grub #44
+    #[category = "Grubs and Mimics"]
     Grub44,
     /// Rescued Grub 45 (Grub)
     /// 
     /// Splits when rescuing grub #45
+    #[category = "Grubs and Mimics"]
     Grub45,
     /// Rescued Grub 46 (Grub)
     /// 
     /// Splits when rescuing grub #46
+    #[category = "Grubs and Mimics"]
     Grub46,
     /// Rescued Any Grub (Grub)
     /// 
     /// Splits when rescuing any grub
+    #[category = "Grubs and Mimics"]
     OnObtainGrub,
     /// Rescued Grub Basin Dive (Grub)
     /// 
     /// Splits when rescuing the grub in Abyss_17
+    #[category = "Grubs and Mimics"]
     GrubBasinDive,
     /// Rescued Grub Basin Wings (Grub)
     /// 
     /// Splits when rescuing the grub in Abyss_19
+    #[category = "Grubs and Mimics"]
     GrubBasinWings,
     /// Rescued Grub City Below Love Tower (Grub)
     /// 
     /// Splits when rescuing the grub in Ruins2_07
+    #[category = "Grubs and Mimics"]
     GrubCityBelowLoveTower,
     /// Rescued Grub City Below Sanctum (Grub)
     /// 
     /// Splits when rescuing the grub in Ruins1_05
+    #[category = "Grubs and Mimics"]
     GrubCityBelowSanctum,
     /// Rescued Grub City Guard House (Grub)
     /// 
     /// Splits when rescuing the grub in Ruins_House_01
+    #[category = "Grubs and Mimics"]
     GrubCityGuardHouse,
     /// Rescued Grub City Sanctum (Grub)
     /// 
     /// Splits when rescuing the grub in Ruins1_32
+    #[category = "Grubs and Mimics"]
     GrubCitySanctum,
     /// Rescued Grub City Spire (Grub)
     /// 
     /// Splits when rescuing the grub in Ruins2_03
+    #[category = "Grubs and Mimics"]
     GrubCitySpire,
     /// Rescued Grub Cliffs Baldur Shell (Grub)
     /// 
     /// Splits when rescuing the grub in Fungus1_28
+    #[category = "Grubs and Mimics"]
     GrubCliffsBaldurShell,
     /// Rescued Grub Crossroads Acid (Grub)
     /// 
     /// Splits when rescuing the grub in Crossroads_35
+    #[category = "Grubs and Mimics"]
     GrubCrossroadsAcid,
     /// Rescued Grub Crossroads Guarded (Grub)
     /// 
     /// Splits when rescuing the grub in Crossroads_48
+    #[category = "Grubs and Mimics"]
     GrubCrossroadsGuarded,
     /// Rescued Grub Crossroads Spikes (Grub)
     /// 
     /// Splits when rescuing the grub in Crossroads_31
+    #[category = "Grubs and Mimics"]
     GrubCrossroadsSpikes,
     /// Rescued Grub Crossroads Vengefly (Grub)
     /// 
     /// Splits when rescuing the grub in Crossroads_05
+    #[category = "Grubs and Mimics"]
     GrubCrossroadsVengefly,
     /// Rescued Grub Crossroads Wall (Grub)
     /// 
     /// Splits when rescuing the grub in Crossroads_03
+    #[category = "Grubs and Mimics"]
     GrubCrossroadsWall,
     /// Rescued Grub Crystal Peak Bottom Lever (Grub)
     /// 
     /// Splits when rescuing the grub in Mines_04
+    #[category = "Grubs and Mimics"]
     GrubCrystalPeaksBottomLever,
     /// Rescued Grub Crystal Peak Crown (Grub)
     /// 
     /// Splits when rescuing the grub in Mines_24
+    #[category = "Grubs and Mimics"]
     GrubCrystalPeaksCrown,
     /// Rescued Grub Crystal Peak Crushers (Grub)
     /// 
     /// Splits when rescuing the grub in Mines_19
+    #[category = "Grubs and Mimics"]
     GrubCrystalPeaksCrushers,
     /// Rescued Grub Crystal Peak Crystal Heart (Grub)
     /// 
     /// Splits when rescuing the grub in Mines_31
+    #[category = "Grubs and Mimics"]
     GrubCrystalPeaksCrystalHeart,
     /// Rescued Grub Crystal Peak Mimic (Grub)
     /// 
     /// Splits when rescuing the grub in Mines_16
+    #[category = "Grubs and Mimics"]
     GrubCrystalPeaksMimics,
     /// Rescued Grub Crystal Peak Mound (Grub)
     /// 
     /// Splits when rescuing the grub in Mines_35
+    #[category = "Grubs and Mimics"]
     GrubCrystalPeaksMound,
     /// Rescued Grub Crystal Peak Spikes (Grub)
     /// 
     /// Splits when rescuing the grub in Mines_03
+    #[category = "Grubs and Mimics"]
     GrubCrystalPeaksSpikes,
     /// Rescued Grub Deepnest Beast's Den (Grub)
     /// 
     /// Splits when rescuing the grub in Deepnest_Spider_Town
+    #[category = "Grubs and Mimics"]
     GrubDeepnestBeastsDen,
     /// Rescued Grub Deepnest Dark (Grub)
     /// 
     /// Splits when rescuing the grub in Deepnest_39
+    #[category = "Grubs and Mimics"]
     GrubDeepnestDark,
     /// Rescued Grub Deepnest Mimics (Grub)
     /// 
     /// Splits when rescuing the grub in Deepnest_36
+    #[category = "Grubs and Mimics"]
     GrubDeepnestMimics,
     /// Rescued Grub Deepnest Nosk (Grub)
     /// 
     /// Splits when rescuing the grub in Deepnest_31
+    #[category = "Grubs and Mimics"]
     GrubDeepnestNosk,
     /// Rescued Grub Deepnest Spikes (Grub)
     /// 
     /// Splits when rescuing the grub in Deepnest_03
+    #[category = "Grubs and Mimics"]
     GrubDeepnestSpikes,
     /// Rescued Grub Fog Canyon Archives (Grub)
     /// 
     /// Splits when rescuing the grub in Fungus3_47
+    #[category = "Grubs and Mimics"]
     GrubFogCanyonArchives,
     /// Rescued Grub Fungal Bouncy (Grub)
     /// 
     /// Splits when rescuing the grub in Fungus2_18
+    #[category = "Grubs and Mimics"]
     GrubFungalBouncy,
     /// Rescued Grub Fungal Spore Shroom (Grub)
     /// 
     /// Splits when rescuing the grub in Fungus2_20
+    #[category = "Grubs and Mimics"]
     GrubFungalSporeShroom,
     /// Rescued Grub Greenpath Cornifer (Grub)
     /// 
     /// Splits when rescuing the grub in Fungus1_06
+    #[category = "Grubs and Mimics"]
     GrubGreenpathCornifer,
     /// Rescued Grub Greenpath Hunter (Grub)
     /// 
     /// Splits when rescuing the grub in Fungus1_07
+    #[category = "Grubs and Mimics"]
     GrubGreenpathHunter,
     /// Rescued Grub Greenpath Moss Knight (Grub)
     /// 
     /// Splits when rescuing the grub in Fungus1_21
+    #[category = "Grubs and Mimics"]
     GrubGreenpathMossKnight,
     /// Rescued Grub Greenpath Vessel Fragment (Grub)
     /// 
     /// Splits when rescuing the grub in Fungus1_13
+    #[category = "Grubs and Mimics"]
     GrubGreenpathVesselFragment,
     /// Rescued Grub Hive External (Grub)
     /// 
     /// Splits when rescuing the grub in Hive_03
+    #[category = "Grubs and Mimics"]
     GrubHiveExternal,
     /// Rescued Grub Hive Internal (Grub)
     /// 
     /// Splits when rescuing the grub in Hive_04
+    #[category = "Grubs and Mimics"]
     GrubHiveInternal,
     /// Rescued Grub Kingdom's Edge Center (Grub)
     /// 
     /// Splits when rescuing the grub in Deepnest_East_11
+    #[category = "Grubs and Mimics"]
     GrubKingdomsEdgeCenter,
     /// Rescued Grub Kingdom's Edge Oro (Grub)
     /// 
     /// Splits when rescuing the grub in Deepnest_East_14
+    #[category = "Grubs and Mimics"]
     GrubKingdomsEdgeOro,
     /// Rescued Grub Queen's Gardens Below Stag (Grub)
     /// 
     /// Splits when rescuing the grub in Fungus3_10
+    #[category = "Grubs and Mimics"]
     GrubQueensGardensBelowStag,
     /// Rescued Grub Queen's Gardens Upper (Grub)
     /// 
     /// Splits when rescuing the grub in Fungus3_22
+    #[category = "Grubs and Mimics"]
     GrubQueensGardensUpper,
     /// Rescued Grub Queen's Gardens White Lady (Grub)
     /// 
     /// Splits when rescuing the grub in Fungus3_48
+    #[category = "Grubs and Mimics"]
     GrubQueensGardensWhiteLady,
     /// Rescued Grub Resting Grounds Crypts (Grub)
     /// 
     /// Splits when rescuing the grub in RestingGrounds_10
+    #[category = "Grubs and Mimics"]
     GrubRestingGroundsCrypts,
     /// Rescued Grub Waterways Center (Grub)
     /// 
     /// Splits when rescuing the grub in Waterways_04
+    #[category = "Grubs and Mimics"]
     GrubWaterwaysCenter,
     /// Rescued Grub Waterways Hwurmps (Grub)
     /// 
     /// Splits when rescuing the grub in Waterways_14
+    #[category = "Grubs and Mimics"]
     GrubWaterwaysHwurmps,
     /// Rescued Grub Waterways Isma (Grub)
     /// 
     /// Splits when rescuing the grub in Waterways_13
+    #[category = "Grubs and Mimics"]
     GrubWaterwaysIsma,
     /// Mimic 1 (Killed)
     /// 
     /// Splits when rescuing mimic #1
+    #[category = "Grubs and Mimics"]
     Mimic1,
     /// Mimic 2 (Killed)
     /// 
     /// Splits when rescuing mimic #2
+    #[category = "Grubs and Mimics"]
     Mimic2,
     /// Mimic 3 (Killed)
     /// 
     /// Splits when rescuing mimic #3
+    #[category = "Grubs and Mimics"]
     Mimic3,
     /// Mimic 4 (Killed)
     /// 
     /// Splits when rescuing mimic #4
+    #[category = "Grubs and Mimics"]
     Mimic4,
     /// Mimic 5 (Killed)
     /// 
     /// Splits when rescuing mimic #5
+    #[category = "Grubs and Mimics"]
     Mimic5,
     // endregion: Grubs and Mimics
 
@@ -1318,297 +1647,423 @@ pub enum Split {
     /// 100 Essence (Essence)
     /// 
     /// Splits upon obtaining 100 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence100,
     /// 200 Essence (Essence)
     /// 
     /// Splits upon obtaining 200 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence200,
     /// 300 Essence (Essence)
     /// 
     /// Splits upon obtaining 300 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence300,
     /// 400 Essence (Essence)
     /// 
     /// Splits upon obtaining 400 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence400,
     /// 500 Essence (Essence)
     /// 
     /// Splits upon obtaining 500 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence500,
     /// 600 Essence (Essence)
     /// 
     /// Splits upon obtaining 600 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence600,
     /// 700 Essence (Essence)
     /// 
     /// Splits upon obtaining 700 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence700,
     /// 800 Essence (Essence)
     /// 
     /// Splits upon obtaining 800 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence800,
     /// 900 Essence (Essence)
     /// 
     /// Splits upon obtaining 900 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence900,
     /// 1000 Essence (Essence)
     /// 
     /// Splits upon obtaining 1000 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence1000,
     /// 1100 Essence (Essence)
     /// 
     /// Splits upon obtaining 1100 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence1100,
     /// 1200 Essence (Essence)
     /// 
     /// Splits upon obtaining 1200 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence1200,
     /// 1300 Essence (Essence)
     /// 
     /// Splits upon obtaining 1300 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence1300,
     /// 1400 Essence (Essence)
     /// 
     /// Splits upon obtaining 1400 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence1400,
     /// 1500 Essence (Essence)
     /// 
     /// Splits upon obtaining 1500 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence1500,
     /// 1600 Essence (Essence)
     /// 
     /// Splits upon obtaining 1600 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence1600,
     /// 1700 Essence (Essence)
     /// 
     /// Splits upon obtaining 1700 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence1700,
     /// 1800 Essence (Essence)
     /// 
     /// Splits upon obtaining 1800 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence1800,
     /// 1900 Essence (Essence)
     /// 
     /// Splits upon obtaining 1900 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence1900,
     /// 2000 Essence (Essence)
     /// 
     /// Splits upon obtaining 2000 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence2000,
     /// 2100 Essence (Essence)
     /// 
     /// Splits upon obtaining 2100 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence2100,
     /// 2200 Essence (Essence)
     /// 
     /// Splits upon obtaining 2200 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence2200,
     /// 2300 Essence (Essence)
     /// 
     /// Splits upon obtaining 2300 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence2300,
     /// 2400 Essence (Essence)
     /// 
     /// Splits upon obtaining 2400 Essence
+    #[category = "Essence, Trees, and Ghosts"]
     Essence2400,
     /// Dream Nail Marissa (Obtain)
     /// 
     /// Splits when obtaining the essence from Marissa
+    #[category = "Essence, Trees, and Ghosts"]
     OnObtainGhostMarissa,
     /// Dream Nail Caelif and Fera (Obtain)
     /// 
     /// Splits when obtaining the essence from Caelif and Fera Orthop
+    #[category = "Essence, Trees, and Ghosts"]
     OnObtainGhostCaelifFera,
     /// Dream Nail Poggy (Obtain)
     /// 
     /// Splits when obtaining the essence from Poggy Thorax
+    #[category = "Essence, Trees, and Ghosts"]
     OnObtainGhostPoggy,
     /// Dream Nail Gravedigger (Obtain)
     /// 
     /// Splits when obtaining the essence from Gravedigger
+    #[category = "Essence, Trees, and Ghosts"]
     OnObtainGhostGravedigger,
     /// Dream Nail Joni (Obtain)
     /// 
     /// Splits when obtaining the essence from Blue Child Joni
+    #[category = "Essence, Trees, and Ghosts"]
     OnObtainGhostJoni,
     // TODO: resolve possible confounding essence sources for Cloth, Vespa, and Revek
     // endregion: Essence, Trees, and Ghosts
 
+    // region: Completion
+    /// 100% Completion (Completion)
+    /// 
+    /// Splits when the game completion percentage reaches 100%
+    #[category = "Completion"]
+    Completion100,
+    /// 106% Completion (Completion)
+    /// 
+    /// Splits when the game completion percentage reaches 106%
+    #[category = "Completion"]
+    Completion106,
+    /// 112% Completion (Completion)
+    /// 
+    /// Splits when the game completion percentage reaches 112%
+    #[category = "Completion"]
+    Completion112,
+    // endregion: Completion
+
+    // region: Map Zones
+    /// Enter Map Zone (Transition)
+    /// 
+    /// Splits when entering the Map Zone chosen below the split
+    #[category = "Map Zones"]
+    EnterMapZone(MapZone),
+    /// Leave Map Zone (Transition)
+    /// 
+    /// Splits when leaving the Map Zone chosen below the split
+    #[category = "Map Zones"]
+    LeaveMapZone(MapZone),
+    // endregion: Map Zones
+
+    // region: Scene Transitions
+    /// Scene Transition (Transition)
+    /// 
+    /// Splits on a transition from a scene matching the From Scene chosen below the split,
+    /// to a scene matching the To Scene chosen below the split
+    #[category = "Scene Transitions"]
+    SceneTransition { old: ScenePattern, new: ScenePattern },
+    // endregion: Scene Transitions
+
+    // region: PlayerData
+    /// PlayerData Int (PlayerData)
+    /// 
+    /// Splits when the PlayerData int field set below the split reaches the value set below it
+    #[category = "PlayerData"]
+    PlayerDataInt { field: String, value: i32 },
+    /// PlayerData Bool (PlayerData)
+    /// 
+    /// Splits when the PlayerData bool field set below the split becomes true
+    #[category = "PlayerData"]
+    PlayerDataBool { field: String },
+    // endregion: PlayerData
+
     // region: Dirtmouth
     /// King's Pass (Transition)
     /// 
     /// Splits when entering Dirtmouth from King's Pass
+    #[category = "Dirtmouth"]
     KingsPass,
     /// Dirtmouth (Transition)
     /// 
     /// Splits on any transition into Dirtmouth Town
+    #[category = "Dirtmouth"]
     EnterDirtmouth,
     /// Dirtmouth (Area)
     /// 
     /// Splits when entering Dirtmouth text first appears
+    #[category = "Dirtmouth"]
     Dirtmouth,
+    #[category = "Dirtmouth"]
     SlyShopExit,
     /// Elderbug Flower Quest (NPC)
     /// 
     /// Splits when giving the flower to the Elderbug
+    #[category = "Dirtmouth"]
     ElderbugFlower,
     /// Cornifer at Home (Transition)
     /// 
     /// Splits when entering Iselda's hut while Cornifer is sleeping
+    #[category = "Dirtmouth"]
     CorniferAtHome,
     /// Enter Troupe Master Grimm (Transition)
     /// 
     /// Splits when entering Grimm tent with requirements to trigger Troupe Master Grimm boss
+    #[category = "Dirtmouth"]
     EnterTMG,
     /// Troupe Master Grimm (Boss)
     /// 
     /// Splits when killing Troupe Master Grimm
+    #[category = "Dirtmouth"]
     TroupeMasterGrimm,
     /// NKG Dream (Transition)
     /// 
     /// Splits on transition into Nightmare King Grimm dream
+    #[category = "Dirtmouth"]
     EnterNKG,
     /// Nightmare King Grimm (Boss)
     /// 
     /// Splits when killing Nightmare King Grimm
+    #[category = "Dirtmouth"]
     NightmareKingGrimm,
     /// Grey Prince Zote (Boss)
     /// 
     /// Splits when killing Grey Prince
+    #[category = "Dirtmouth"]
     GreyPrince,
     /// Grey Prince Zote (Essence)
     /// 
     /// Splits when getting Grey Prince Zote essence
+    #[category = "Dirtmouth"]
     GreyPrinceEssence,
     // endregion: Dirtmouth
     // region: Crossroads
     /// Forgotten Crossroads (Area)
     /// 
     /// Splits when entering Forgotten Crossroads text first appears
+    #[category = "Crossroads"]
     ForgottenCrossroads,
     /// Infected Crossroads (Area)
     /// 
     /// Splits when entering Infected Crossroads text first appears
+    #[category = "Crossroads"]
     InfectedCrossroads,
     /// Menderbug (Killed)
     /// 
     /// Splits when killing Menderbug
+    #[category = "Crossroads"]
     MenderBug,
     /// Enter Brooding Mawlek (Transition)
     /// 
     /// Splits when entering the Brooding Mawlek arena transition in Forgotten Crossroads
+    #[category = "Crossroads"]
     EnterBroodingMawlek,
     /// Brooding Mawlek (Boss)
     /// 
     /// Splits when killing Brooding Mawlek
+    #[category = "Crossroads"]
     BroodingMawlek,
     /// Ancestral Mound (Transition)
     /// 
     /// Splits on transition into Ancestral Mound
+    #[category = "Crossroads"]
     AncestralMound,
     /// Gruz Mother (Boss)
     /// 
     /// Splits when killing Gruz Mother
+    #[category = "Crossroads"]
     GruzMother,
     /// Sly Rescued (NPC)
     /// 
     /// Splits when saving Sly
+    #[category = "Crossroads"]
     SlyRescued,
     /// False Knight (Boss)
     /// 
     /// Splits when killing False Knight
+    #[category = "Crossroads"]
     FalseKnight,
     /// Failed Champion (Boss)
     /// 
     /// Splits when killing Failed Champion
+    #[category = "Crossroads"]
     FailedKnight,
     /// Failed Champion (Essence)
     /// 
     /// Splits when getting Failed Champion essence
+    #[category = "Crossroads"]
     FailedChampionEssence,
     /// Salubra's Blessing (Item)
     /// 
     /// Splits when obtaining Salubra's Blessing
+    #[category = "Crossroads"]
     SalubrasBlessing,
     /// Salubra Exit (Transition)
     /// 
     /// Splits on the transition out of Salubra's Hut
+    #[category = "Crossroads"]
     SalubraExit,
+    #[category = "Crossroads"]
     EnterHollowKnight,
     /// Chains Broken - Hollow Knight (Event)
     /// 
     /// Splits at the end of the first Hollow Knight scream after the chains are broken
+    #[category = "Crossroads"]
     UnchainedHollowKnight,
     /// Segment Practice - THK (Boss)
     /// 
     /// Splits when killing The Hollow Knight
+    #[category = "Crossroads"]
     HollowKnightBoss,
     /// Radiance Dream Entry (Event)
     /// 
     /// Splits upon entering the Radiance dream
     // TODO: Skips upon killing the Hollow Knight (requires ordered splits)
+    #[category = "Crossroads"]
     HollowKnightDreamnail,
     /// Segment Practice - Radiance (Boss)
     /// 
     /// Splits when killing The Radiance
+    #[category = "Crossroads"]
     RadianceBoss,
     // endregion: Crossroads
     // region: Greenpath
     /// Greenpath (Transition)
     /// 
     /// Splits when entering Greenpath
+    #[category = "Greenpath"]
     EnterGreenpath,
     /// Greenpath (Area)
     /// 
     /// Splits when entering Greenpath text first appears
+    #[category = "Greenpath"]
     Greenpath,
     /// Moss Knight (Mini Boss)
     /// 
     /// Splits when killing Moss Knight
+    #[category = "Greenpath"]
     MossKnight,
     /// Zote Rescued - Vengefly King (Mini Boss)
     /// 
     /// Splits when rescuing Zote from the Vengefly King
+    #[category = "Greenpath"]
     Zote1,
     /// Vengefly King Killed (Transition)
     /// 
     /// Splits on transition after Vengefly King in Greenpath killed
+    #[category = "Greenpath"]
     VengeflyKingTrans,
     /// Enter Hornet 1 (Transition)
     /// 
     /// Splits when entering Hornet boss arena transition in Greenpath
+    #[category = "Greenpath"]
     EnterHornet1,
     /// Hornet 1 (Boss)
     /// 
     /// Splits when killing Hornet Protector in Greenpath
+    #[category = "Greenpath"]
     Hornet1,
     /// Aluba (Killed)
     /// 
     /// Splits when killing an Aluba
+    #[category = "Greenpath"]
     Aluba,
     /// Hunter's Mark (Item)
     /// 
     /// Splits when obtaining the Hunter's Mark
+    #[category = "Greenpath"]
     HuntersMark,
     /// No Eyes (Boss)
     /// 
     /// Splits when killing No Eyes
+    #[category = "Greenpath"]
     NoEyes,
     /// No Eyes (Essence)
     /// 
     /// Splits when absorbing essence from No Eyes
+    #[category = "Greenpath"]
     NoEyesEssence,
     /// Massive Moss Charger (Boss)
     /// 
     /// Splits when killing Massive Moss Charger
+    #[category = "Greenpath"]
     MegaMossCharger,
     /// Massive Moss Charger Killed (Transition)
     /// 
     /// Splits on transition after Massive Moss Charger is killed
+    #[category = "Greenpath"]
     MegaMossChargerTrans,
     /// Happy Couple (Event)
     /// 
     /// Splits when talking to Nailsmith in Sheo's hut for the first time
+    #[category = "Greenpath"]
     HappyCouplePlayerDataEvent,
     // endregion: Greenpath
     // region: Fungal
@@ -1617,407 +2072,510 @@ pub enum Split {
     /// Splits on transition to Fungal Wastes
     /// 
     /// (Room below Crossroads, right of Queen's Station, left of Waterways or Spore Shroom room)
+    #[category = "Fungal"]
     FungalWastesEntry,
     /// Fungal Wastes (Area)
     /// 
     /// Splits when entering Fungal Wastes text first appears
+    #[category = "Fungal"]
     FungalWastes,
     /// Elder Hu (Boss)
     /// 
     /// Splits when killing Elder Hu
+    #[category = "Fungal"]
     ElderHu,
     /// Elder Hu (Essence)
     /// 
     /// Splits when absorbing essence from Elder Hu
+    #[category = "Fungal"]
     ElderHuEssence,
     /// Elder Hu Killed (Transition)
     /// 
     /// Splits on the transition after killing Elder Hu
+    #[category = "Fungal"]
     ElderHuTrans,
+    #[category = "Fungal"]
     MenuMantisJournal,
     /// Bretta Rescued (NPC)
     /// 
     /// Splits when saving Bretta
+    #[category = "Fungal"]
     BrettaRescued,
     /// Mantis Lords (Boss)
     /// 
     /// Splits when killing Mantis Lords
+    #[category = "Fungal"]
     MantisLords,
     // endregion: Fungal
     // region: Cliffs
     /// Gorb (Boss)
     /// 
     /// Splits when killing Gorb
+    #[category = "Cliffs"]
     Gorb,
     /// Gorb (Essence)
     /// 
     /// Splits when absorbing essence from Gorb
+    #[category = "Cliffs"]
     GorbEssence,
     /// Nightmare Lantern Lit (Event)
     /// 
     /// Splits when initially lighting the Nightmare Lantern
+    #[category = "Cliffs"]
     NightmareLantern,
     /// Nightmare Lantern Destroyed (Event)
     /// 
     /// Splits when destroying the Nightmare Lantern
+    #[category = "Cliffs"]
     NightmareLanternDestroyed,
     // endregion: Cliffs
     // region: Resting Grounds
     /// Blue Lake (Transition)
     /// 
     /// Splits on transition to Blue Lake from either side
+    #[category = "Resting Grounds"]
     BlueLake,
     /// Enter Any Dream (Transition)
     /// 
     /// Splits when entering any dream world
+    #[category = "Resting Grounds"]
     EnterAnyDream,
+    #[category = "Resting Grounds"]
     DreamNailExit,
     /// Resting Grounds (Area)
     /// 
     /// Splits when entering Resting Grounds text first appears
+    #[category = "Resting Grounds"]
     RestingGrounds,
     /// Resting Grounds Stag (Bench)
     /// 
     /// Splits when sitting on the bench at Resting Grounds Stag
+    #[category = "Resting Grounds"]
     BenchRGStag,
     /// Xero (Boss)
     /// 
     /// Splits when killing Xero
+    #[category = "Resting Grounds"]
     Xero,
     /// Xero (Essence)
     /// 
     /// Splits when absorbing essence from Xero
+    #[category = "Resting Grounds"]
     XeroEssence,
     /// Spirit Glade Door (Event)
     /// 
     /// Splits when the Seer opens the Spirits' Glade after bringing back 200 essence
+    #[category = "Resting Grounds"]
     SpiritGladeOpen,
     /// Seer Departs (Event)
     /// 
     /// Splits when the Seer Departs after bringing back 2400 essence
+    #[category = "Resting Grounds"]
     SeerDeparts,
     /// Catacombs Entry (Transition)
     /// 
     /// Splits on entry to the catacombs below Resting Grounds
+    #[category = "Resting Grounds"]
     CatacombsEntry,
     /// Met Grey Mourner (NPC)
     /// 
     /// Splits when talking to Grey Mourner for the first time
+    #[category = "Resting Grounds"]
     MetGreyMourner,
     /// Mourner w/ Seer Ascended (NPC)
     /// 
     /// Splits when both talked to Grey Mourner and Seer has ascended
+    #[category = "Resting Grounds"]
     GreyMournerSeerAscended,
     // endregion: Resting Grounds
     // region: City
     /// City Gate (Event)
     /// 
     /// Splits when using the City Crest to open the gate
+    #[category = "City"]
     CityGateOpen,
     /// City Gate w/ Mantis Lords defeated (Event)
     /// 
     /// To make sure you don't forget Mantis Lords
+    #[category = "City"]
     CityGateAndMantisLords,
     /// City of Tears (Area)
     /// 
     /// Splits when entering City of Tears text first appears
+    #[category = "City"]
     CityOfTears,
     /// Gorgeous Husk (Killed)
     /// 
     /// Splits when killing Gorgeous Husk
+    #[category = "City"]
     GorgeousHusk,
     /// Gorgeous Husk Killed (Transition)
     /// 
     /// Splits on transition after Gorgeous Husk defeated
+    #[category = "City"]
     TransGorgeousHusk,
     /// Main Menu w/ Ghusk (Menu)
     /// 
     /// Splits on transition to the main menu after Gorgeous Husk defeated
+    #[category = "City"]
     MenuGorgeousHusk,
     /// Rafters (Transition)
     /// 
     /// Splits on any transition into the City Rafters room
+    #[category = "City"]
     EnterRafters,
     /// Lemm Shop (NPC)
     /// 
     /// Splits when talking to Lemm in the shop for the first time
+    #[category = "City"]
     Lemm2,
     /// Sanctum Bench (Toll)
     /// 
     /// Splits when buying City/Sanctum toll bench by Cornifer's location
+    #[category = "City"]
     TollBenchCity,
     /// Soul Sanctum (Transition)
     /// 
     /// Splits when entering Soul Sanctum
+    #[category = "City"]
     EnterSanctum,
     /// Soul Sanctum w/ Shade Soul (Transition)
     /// 
     /// Splits when entering Soul Sanctum after obtaining Shade Soul
+    #[category = "City"]
     EnterSanctumWithShadeSoul,
     /// Enter Soul Master (Transition)
     /// 
     /// Splits when entering Soul Master boss arena transition
+    #[category = "City"]
     EnterSoulMaster,
     /// Soul Master Encountered (Boss)
     /// 
     /// Splits when Soul Master is activated the first time as the gate closes
+    #[category = "City"]
     SoulMasterEncountered,
     /// Soul Master - Fake Spell Pickup (Boss)
     /// 
     /// Splits when triggering Soul Master phase 2 the first time
+    #[category = "City"]
     SoulMasterPhase1,
     /// Soul Master (Boss)
     /// 
     /// Splits when killing Soul Master
+    #[category = "City"]
     SoulMaster,
     /// Soul Tyrant (Boss)
     /// 
     /// Splits when killing Soul Tyrant
+    #[category = "City"]
     SoulTyrant,
     /// Soul Tyrant (Essence)
     /// 
     /// Splits when getting Soul Tyrant essence
+    #[category = "City"]
     SoulTyrantEssence,
+    #[category = "City"]
     MenuStoreroomsSimpleKey,
+    #[category = "City"]
     EnterBlackKnight,
     /// Chandelier - Watcher Knights (Event)
     /// 
     /// Splits when dropping the chandelier on one of the Watcher Knights
+    #[category = "City"]
     WatcherChandelier,
     /// Watcher Knight (Boss)
     /// 
     /// Splits when killing Watcher Knights
+    #[category = "City"]
     BlackKnight,
     /// Watcher Knight Killed (Transition)
     /// 
     /// Splits on the transition after killing Watcher Knights
+    #[category = "City"]
     BlackKnightTrans,
     /// Tower of Love (Transition)
     /// 
     /// Splits when entering the Tower of Love
+    #[category = "City"]
     EnterLoveTower,
     /// Collector (Boss)
     /// 
     /// Splits when killing Collector
+    #[category = "City"]
     Collector,
     /// Collector Defeated (Transition)
     /// 
     /// Splits on transition after defeating the Collector
+    #[category = "City"]
     TransCollector,
     /// Nailsmith Killed (Event)
     /// 
     /// Splits when Nailsmith is killed
+    #[category = "City"]
     NailsmithKilled,
     // endregion: City
     // region: Peak
     /// Crystal Peak Entry (Transition)
     /// 
     /// Splits on transition to the room where the dive and toll entrances meet, or the room right of Dirtmouth
+    #[category = "Peak"]
     CrystalPeakEntry,
     /// Crystal Peak (Area)
     /// 
     /// Splits when entering Crystal Peak text first appears
+    #[category = "Peak"]
     CrystalPeak,
     /// Husk Miner (Killed)
     /// 
     /// Splits when killing a Husk Miner
+    #[category = "Peak"]
     HuskMiner,
+    #[category = "Peak"]
     MenuSlyKey,
     /// Crystal Guardian (Boss)
     /// 
     /// Splits when killing the Crystal Guardian
+    #[category = "Peak"]
     CrystalGuardian1,
     /// Enraged Guardian (Boss)
     /// 
     /// Splits when killing the Enraged Guardian
+    #[category = "Peak"]
     CrystalGuardian2,
     /// Hallownest's Crown (Transition)
     /// 
     /// Splits on transition into the room with the Whispering Root at the base of Hallownest's Crown
+    #[category = "Peak"]
     EnterCrown,
     /// Crystal Mound Exit (Transition)
     /// 
     /// Splits on transition from Crystal Mound
+    #[category = "Peak"]
     CrystalMoundExit,
     /// Crystal Peak Lift Opened (Event)
     /// 
     /// Splits when opening the lever for the lift between Dirtmouth and Crystal Peak
+    #[category = "Peak"]
     MineLiftOpened,
     // endregion: Peak
     // region: Waterways
     /// Waterways Manhole (Toll)
     /// 
     /// Splits when opening the Waterways Manhole
+    #[category = "Waterways"]
     WaterwaysManhole,
     /// Waterways (Transition)
     /// 
     /// Splits on transition to Waterways
+    #[category = "Waterways"]
     WaterwaysEntry,
     /// Royal Waterways (Area)
     /// 
     /// Splits when entering Royal Waterways text first appears
+    #[category = "Waterways"]
     RoyalWaterways,
     /// Dung Defender (Boss)
     /// 
     /// Splits when killing Dung Defender
+    #[category = "Waterways"]
     DungDefender,
+    #[category = "Waterways"]
     DungDefenderExit,
     /// White Defender (Boss)
     /// 
     /// Splits when killing White Defender
+    #[category = "Waterways"]
     WhiteDefender,
     /// White Defender (Essence)
     /// 
     /// Splits when getting White Defender essence
+    #[category = "Waterways"]
     WhiteDefenderEssence,
     /// Met Emilitia (Event)
     /// 
     /// Splits when talking to Emilitia for the first time
+    #[category = "Waterways"]
     MetEmilitia,
     /// Flukemarm (Boss)
     /// 
     /// Splits when killing Flukemarm
+    #[category = "Waterways"]
     Flukemarm,
     /// Junk Pit (Transition)
     /// 
     /// Splits on transition into Junk Pit
+    #[category = "Waterways"]
     EnterJunkPit,
     // endregion: Waterways
     // region: Basin
     /// Ancient Basin (Transition)
     /// 
     /// Splits on transition to Ancient Basin
+    #[category = "Basin"]
     BasinEntry,
     /// Ancient Basin (Area)
     /// 
     /// Splits when entering Ancient Basin text first appears
+    #[category = "Basin"]
     Abyss,
     /// Saved Cloth (Event)
     /// 
     /// Splits when saving Cloth in Ancient Basin
+    #[category = "Basin"]
     SavedCloth,
     /// Basin Bench (Toll)
     /// 
     /// Splits when buying Ancient Basin toll bench
+    #[category = "Basin"]
     TollBenchBasin,
+    #[category = "Basin"]
     Abyss19from18,
     /// Broken Vessel (Boss)
     /// 
     /// Splits when killing Broken Vessel
+    #[category = "Basin"]
     BrokenVessel,
     /// Broken Vessel (Transition)
     /// 
     /// Splits on any non-death transition after defeating Broken Vessel
+    #[category = "Basin"]
     BrokenVesselTrans,
     /// Lost Kin (Boss)
     /// 
     /// Splits when killing Lost Kin
+    #[category = "Basin"]
     LostKin,
     /// Lost Kin (Essence)
     /// 
     /// Splits when getting Lost Kin essence
+    #[category = "Basin"]
     LostKinEssence,
     // endregion: Basin
     // region: White Palace
     /// White Palace Entry (Transition)
     /// 
     /// Splits when entering the first White Palace scene
+    #[category = "White Palace"]
     WhitePalaceEntry,
     /// White Palace - Lower Entry (Room)
     /// 
     /// Splits on transition to White_Palace_01
+    #[category = "White Palace"]
     WhitePalaceLowerEntry,
     /// White Palace (Area)
     /// 
     /// Splits when entering White Palace text for the first time
+    #[category = "White Palace"]
     WhitePalace,
     /// White Palace - Lower Orb (Room)
     /// 
     /// Splits on transition to White_Palace_02
+    #[category = "White Palace"]
     WhitePalaceLowerOrb,
     /// White Palace - Lower Orb (Lever)
     /// 
     /// Splits when lighting the orb in White Palace lowest floor
+    #[category = "White Palace"]
     WhitePalaceOrb1,
     /// White Palace - Atrium (Room)
     /// 
     /// Splits on any transition to White_Palace_03_Hub
+    #[category = "White Palace"]
     WhitePalaceAtrium,
     /// White Palace - Left Entry (Room)
     /// 
     /// Splits on transition to White_Palace_04
+    #[category = "White Palace"]
     WhitePalaceLeftEntry,
     /// White Palace - Left Midpoint (Room)
     /// 
     /// Splits on transition between White_Palace_04 and _14
+    #[category = "White Palace"]
     WhitePalaceLeftWingMid,
     /// White Palace - Left Orb (Lever)
     /// 
     /// Splits when lighting the orb in White Palace left wing
+    #[category = "White Palace"]
     WhitePalaceOrb3,
     /// White Palace - Right Side Entry (Room)
     /// 
     /// Splits on transition between White_Palace_03_Hub and _15
+    #[category = "White Palace"]
     WhitePalaceRightEntry,
     /// White Palace - Right Side Climb (Room)
     /// 
     /// Splits on transition between White_Palace_05 and _16
+    #[category = "White Palace"]
     WhitePalaceRightClimb,
     /// White Palace - Right Side Saw Squeeze (Room)
     /// 
     /// Splits on transition between White_Palace_16 and _05
+    #[category = "White Palace"]
     WhitePalaceRightSqueeze,
     /// White Palace - Right Side Exit (Room)
     /// 
     /// Splits on transition between White_Palace_05 and _15
+    #[category = "White Palace"]
     WhitePalaceRightDone,
     /// White Palace - Right Orb (Lever)
     /// 
     /// Splits when lighting the orb in White Palace right wing
+    #[category = "White Palace"]
     WhitePalaceOrb2,
     /// White Palace - Top Entry (Room)
     /// 
     /// Splits on transition between White_Palace_03_Hub and _06
+    #[category = "White Palace"]
     WhitePalaceTopEntry,
     /// Path of Pain Room 1 (Room)
     /// 
     /// Splits on transition to the first room in PoP (entry to PoP)
+    #[category = "White Palace"]
     PathOfPainEntry,
     /// Path of Pain Room 2 (Room)
     /// 
     /// Splits on transition to the second room in PoP
+    #[category = "White Palace"]
     PathOfPainTransition1,
     /// Path of Pain Room 3 (Room)
     /// 
     /// Splits on transition to the third room in PoP
+    #[category = "White Palace"]
     PathOfPainTransition2,
     /// Path of Pain Room 4 (Room)
     /// 
     /// Splits on transition to the fourth room in PoP (Final room)
+    #[category = "White Palace"]
     PathOfPainTransition3,
     /// Path of Pain (Completed)
     /// 
     /// Splits when completing the Path of Pain in White Palace
+    #[category = "White Palace"]
     PathOfPain,
     /// White Palace - Top Cursed Cycle (Room)
     /// 
     /// Splits on transition between White_Palace_06 and _07
+    #[category = "White Palace"]
     WhitePalaceTopClimb,
     /// White Palace - Top Lever (Room)
     /// 
     /// Splits on transition between White_Palace_07 and _12
+    #[category = "White Palace"]
     WhitePalaceTopLeverRoom,
     /// White Palace - Top Final Platforming (Room)
     /// 
     /// Splits on transition between White_Palace_12 and _13
+    #[category = "White Palace"]
     WhitePalaceTopLastPlats,
     /// White Palace - Workshop (Area)
     /// 
     /// Splits when visiting the secret room in White Palace
+    #[category = "White Palace"]
     WhitePalaceSecretRoom,
     /// White Palace - Throne Room (Room)
     /// 
     /// Splits on transition between White_Palace_13 and _09
+    #[category = "White Palace"]
     WhitePalaceThroneRoom,
     // endregion: White Palace
     // region: Kingdom's Edge
@@ -2028,485 +2586,872 @@ pub enum Split {
     //           or just the King's Station entrance?
     //           Maybe the room off the side of the RG elevator shouldn't count,
     //           but what about the Tram entrance?
+    #[category = "Kingdom's Edge"]
     KingdomsEdgeEntry,
     /// Kingdom's Edge (Area)
     /// 
     /// Splits when entering Kingdom's Edge text first appears
+    #[category = "Kingdom's Edge"]
     KingdomsEdge,
     /// Hive (Transition)
     /// 
     /// Splits on transition to Hive
+    #[category = "Kingdom's Edge"]
     HiveEntry,
     /// Hive (Area)
     /// 
     /// Splits when entering Hive text first appears
+    #[category = "Kingdom's Edge"]
     Hive,
     /// Enter Hive Knight (Transition)
     /// 
     /// Splits when entering Hive Knight boss arena transition
+    #[category = "Kingdom's Edge"]
     EnterHiveKnight,
     /// Hive Knight (Boss)
     /// 
     /// Splits when killing Hive Knight
+    #[category = "Kingdom's Edge"]
     HiveKnight,
     /// Great Hopper (Killed)
     /// 
     /// Splits when killing a Great Hopper
+    #[category = "Kingdom's Edge"]
     GreatHopper,
     /// Enter Hornet 2 (Transition)
     /// 
     /// Splits when entering Hornet boss arena transition in Kingdom's Edge
+    #[category = "Kingdom's Edge"]
     EnterHornet2,
     /// Hornet 2 (Boss)
     /// 
     /// Splits when killing Hornet Sentinel in Kingdom's Edge
+    #[category = "Kingdom's Edge"]
     Hornet2,
     /// Markoth (Boss)
     /// 
     /// Splits when killing Markoth
+    #[category = "Kingdom's Edge"]
     Markoth,
     /// Markoth (Essence)
     /// 
     /// Splits when absorbing essence from Markoth
+    #[category = "Kingdom's Edge"]
     MarkothEssence,
     // endregion: Kingdom's Edge
     // region: Colosseum
     /// Little Fool (NPC)
     /// 
     /// Splits when talking to the Little Fool for the first time
+    #[category = "Colosseum"]
     LittleFool,
     /// Colosseum Unlocked 1 (Trial)
     /// 
     /// Splits when the knight unlocks the Trial of the Warrior at Little Fool
+    #[category = "Colosseum"]
     ColosseumBronzeUnlocked,
     /// Colosseum Entrance 1 (Transition)
     /// 
     /// Splits on the transition into the Trial of the Warrior
+    #[category = "Colosseum"]
     ColosseumBronzeEntry,
     /// Colosseum (Area)
     /// 
     /// Splits when entering Colosseum text first appears
+    #[category = "Colosseum"]
     Colosseum,
     /// Zote Defeated - Colosseum (Mini Boss)
     /// 
     /// Splits when defeating Zote in the Colosseum
+    #[category = "Colosseum"]
     ZoteKilled,
     /// Colosseum Fight 1 (Trial)
     /// 
     /// Splits when beating the Trial of the Warrior
+    #[category = "Colosseum"]
     ColosseumBronze,
     /// Colosseum Exit 1 (Transition)
     /// 
     /// Splits on the transition out of the trial, or in the load-in after quitout
+    #[category = "Colosseum"]
     ColosseumBronzeExit,
     /// Colosseum Unlocked 2 (Trial)
     /// 
     /// Splits when the knight unlocks the Trial of the Conqueror at Little Fool
+    #[category = "Colosseum"]
     ColosseumSilverUnlocked,
     /// Colosseum Entrance 2 (Transition)
     /// 
     /// Splits on the transition into the Trial of the Conqueror
+    #[category = "Colosseum"]
     ColosseumSilverEntry,
     /// Colosseum Fight 2 (Trial)
     /// 
     /// Splits when beating the Trial of the Conqueror
+    #[category = "Colosseum"]
     ColosseumSilver,
     /// Colosseum Exit 2 (Transition)
     /// 
     /// Splits on the transition out of the trial, or in the load-in after quitout
+    #[category = "Colosseum"]
     ColosseumSilverExit,
     /// Colosseum Unlocked 3 (Trial)
     /// 
     /// Splits when the knight unlocks the Trial of the Fool at Little Fool
+    #[category = "Colosseum"]
     ColosseumGoldUnlocked,
     /// Colosseum Entrance 3 (Transition)
     /// 
     /// Splits on the transition into the Trial of the Warrior
+    #[category = "Colosseum"]
     ColosseumGoldEntry,
     /// God Tamer (Boss)
     /// 
     /// Splits when killing the God Tamer
+    #[category = "Colosseum"]
     GodTamer,
     /// Colosseum Fight 3 (Trial)
     /// 
     /// Splits when beating the Trial of the Warrior
+    #[category = "Colosseum"]
     ColosseumGold,
     /// Colosseum Exit 3 (Transition)
     /// 
     /// Splits on the transition out of the trial, or in the load-in after quitout
+    #[category = "Colosseum"]
     ColosseumGoldExit,
     // endregion: Colosseum
     // region: Fog Canyon
     /// Fog Canyon (Transition)
     /// 
     /// Splits on transition to Fog Canyon
+    #[category = "Fog Canyon"]
     FogCanyonEntry,
     /// Fog Canyon (Area)
     /// 
     /// Splits when entering Fog Canyon text first appears
+    #[category = "Fog Canyon"]
     FogCanyon,
     /// Teachers Archive (Area)
     /// 
     /// Splits when entering Teachers Archive for the first time
+    #[category = "Fog Canyon"]
     TeachersArchive,
     /// Uumuu Encountered (Boss)
     /// 
     /// Splits Uumuu is activated the first time as the gate closes
+    #[category = "Fog Canyon"]
     UumuuEncountered,
     /// Uumuu (Boss)
     /// 
     /// Splits when killing Uumuu
+    #[category = "Fog Canyon"]
     Uumuu,
     // endregion: Fog Canyon
     // region: Queen's Gardens
     /// Queen's Garden Entry (Transition)
     /// 
     /// Splits on transition to QG scene following QGA or above Deepnest
+    #[category = "Queen's Gardens"]
     QueensGardensEntry,
     /// Queen's Gardens (Area)
     /// 
     /// Splits when entering Queen's Gardens text first appears
+    #[category = "Queen's Gardens"]
     QueensGardens,
     /// Queen's Garden Bench (Toll)
     /// 
     /// Splits when buying Queen's Garden toll bench
+    #[category = "Queen's Gardens"]
     TollBenchQG,
     /// Queen's Garden - Post-Upper Arena (Transition)
     /// 
     /// Splits on transition to room after upper arena in QG
+    #[category = "Queen's Gardens"]
     QueensGardensPostArenaTransition,
     /// Flower Quest (Event)
     /// 
     /// Splits when placing the flower at the grave of the Traitors' Child
+    #[category = "Queen's Gardens"]
     FlowerQuest,
     /// Queen's Garden - Frogs (Transition)
     /// 
     /// Splits on transition to QG frogs scene
+    #[category = "Queen's Gardens"]
     QueensGardensFrogsTrans,
     /// Marmu (Boss)
     /// 
     /// Splits when killing Marmu
+    #[category = "Queen's Gardens"]
     Marmu,
     /// Marmu (Essence)
     /// 
     /// Splits when absorbing essence from Marmu
+    #[category = "Queen's Gardens"]
     MarmuEssence,
     /// Traitor Lord (Boss)
     /// 
     /// Splits when killing Traitor Lord
+    #[category = "Queen's Gardens"]
     TraitorLord,
     // endregion: Queen's Gardens
     // region: Deepnest
     /// Deepnest (Transition)
     /// 
     /// Splits on transition into Deepnest
+    #[category = "Deepnest"]
     EnterDeepnest,
     /// Deepnest (Area)
     /// 
     /// Splits when entering Deepnest text first appears
+    #[category = "Deepnest"]
     Deepnest,
     /// Deepnest Spa (Area)
     /// 
     /// Splits when entering the Deepnest Spa area with bench
+    #[category = "Deepnest"]
     DeepnestSpa,
     /// Zote Rescued - Deepnest (Mini Boss)
     /// 
     /// Splits when rescuing Zote in Deepnest
+    #[category = "Deepnest"]
     Zote2,
     /// Tram Deepnest (Tram)
     /// 
     /// Splits when unlocking the tram in Deepnest
+    #[category = "Deepnest"]
     TramDeepnest,
     /// Nosk (Transition)
     /// 
     /// Splits when entering Nosk boss arena transition
+    #[category = "Deepnest"]
     EnterNosk,
     /// Nosk (Boss)
     /// 
     /// Splits when killing Nosk
+    #[category = "Deepnest"]
     Nosk,
     /// Galien (Boss)
     /// 
     /// Splits when killing Galien
+    #[category = "Deepnest"]
     Galien,
     /// Galien (Essence)
     /// 
     /// Splits when absorbing essence from Galien
+    #[category = "Deepnest"]
     GalienEssence,
     /// Trap Bench (Event)
     /// 
     /// Splits when getting the trap bench in Beasts Den
+    #[category = "Deepnest"]
     BeastsDenTrapBench,
     // endregion: Deepnest
     // region: Godhome
     /// God Tuner (Item)
     /// 
     /// Splits when obtaining the God Tuner
+    #[category = "Godhome"]
     GodTuner,
     /// Godhome (Transition)
     /// 
     /// Splits on transition to Godhome
+    #[category = "Godhome"]
     EnterGodhome,
     /// Godhome (Area)
     /// 
     /// Splits when entering Godhome text first appears
+    #[category = "Godhome"]
     Godhome,
     /// Eternal Ordeal Unlocked (Event)
     /// 
     /// Splits when breaking the wall to the Zote statue in Godhome
+    #[category = "Godhome"]
     EternalOrdealUnlocked,
     /// Eternal Ordeal Achieved (Event)
     /// 
     /// Splits when achieving the ordeal (57th Zote killed)
+    #[category = "Godhome"]
     EternalOrdealAchieved,
     /// Pantheon 1-4 (Transition)
     /// 
     /// Splits on entry to any of Pantheon 1 - 4
+    #[category = "Godhome"]
     Pantheon1to4Entry,
     /// Vengefly King (Pantheon)
     /// 
     /// Splits after killing Vengefly King in Pantheon 1 or Pantheon 5
+    #[category = "Godhome"]
     VengeflyKingP,
     /// Gruz Mother (Pantheon)
     /// 
     /// Splits after killing Gruz Mother in Pantheon 1 or Pantheon 5
+    #[category = "Godhome"]
     GruzMotherP,
     /// False Knight (Pantheon)
     /// 
     /// Splits after killing False Knight in Pantheon 1 or Pantheon 5
+    #[category = "Godhome"]
     FalseKnightP,
     /// Massive Moss Charger (Pantheon)
     /// 
     /// Splits after killing Massive Moss Charger in Pantheon 1 or Pantheon 5
+    #[category = "Godhome"]
     MassiveMossChargerP,
     /// Hornet 1 (Pantheon)
     /// 
     /// Splits after killing Hornet Protector in Pantheon 1 or Pantheon 5
+    #[category = "Godhome"]
     Hornet1P,
     /// Godhome Bench (Transition)
     /// 
     /// Splits when leaving a Godhome Bench room
+    #[category = "Godhome"]
     GodhomeBench,
     /// Gorb (Pantheon)
     /// 
     /// Splits after killing Gorb in Pantheon 1 or Pantheon 5
+    #[category = "Godhome"]
     GorbP,
     /// Dung Defender (Pantheon)
     /// 
     /// Splits after killing Dung Defender in Pantheon 1 or Pantheon 5
+    #[category = "Godhome"]
     DungDefenderP,
     /// Soul Warrior (Pantheon)
     /// 
     /// Splits after killing Soul Warrior in Pantheon 1 or Pantheon 5
+    #[category = "Godhome"]
     SoulWarriorP,
     /// Brooding Mawlek (Pantheon)
     /// 
     /// Splits after killing Brooding Mawlek in Pantheon 1 or Pantheon 5
+    #[category = "Godhome"]
     BroodingMawlekP,
     /// Godhome Lore Room (Transition)
     /// 
     /// Splits when leaving a Godhome lore room
+    #[category = "Godhome"]
     GodhomeLoreRoom,
     /// Oro & Mato Nail Bros (Boss)
     /// 
     /// Splits when defeating Brothers Oro & Mato
+    #[category = "Godhome"]
     MatoOroNailBros,
     /// Oro & Mato Nail Bros (Pantheon)
     /// 
     /// Splits after killing Brothers Oro & Mato in Pantheon 1 or Pantheon 5
+    #[category = "Godhome"]
     OroMatoNailBrosP,
     /// Pantheon 1 (Trial)
     /// 
     /// Splits when beating the Pantheon of the Master
+    #[category = "Godhome"]
     Pantheon1,
     /// Xero (Pantheon)
     /// 
     /// Splits after killing Xero in Pantheon 2 or Pantheon 5
+    #[category = "Godhome"]
     XeroP,
     /// Crystal Guardian (Pantheon)
     /// 
     /// Splits after killing Crystal Guardian in Pantheon 2 or Pantheon 5
+    #[category = "Godhome"]
     CrystalGuardianP,
     /// Soul Master (Pantheon)
     /// 
     /// Splits after killing Soul Master in Pantheon 2 or Pantheon 5
+    #[category = "Godhome"]
     SoulMasterP,
     /// Oblobbles (Pantheon)
     /// 
     /// Splits after killing Oblobbles in Pantheon 2 or Pantheon 5
+    #[category = "Godhome"]
     OblobblesP,
     /// Mantis Lords (Pantheon)
     /// 
     /// Splits after killing Mantis Lords in Pantheon 2 or Sisters of Battle Pantheon 5
+    #[category = "Godhome"]
     MantisLordsP,
     /// Marmu (Pantheon)
     /// 
     /// Splits after killing Marmu in Pantheon 2 or Pantheon 5
+    #[category = "Godhome"]
     MarmuP,
     /// Nosk (Pantheon)
     /// 
     /// Splits after killing Nosk in Pantheon 2
+    #[category = "Godhome"]
     NoskP,
     /// Flukemarm (Pantheon)
     /// 
     /// Splits after killing Flukemarm in Pantheon 2 or Pantheon 5
+    #[category = "Godhome"]
     FlukemarmP,
     /// Broken Vessel (Pantheon)
     /// 
     /// Splits after killing Broken Vessel in Pantheon 2 or Pantheon 5
+    #[category = "Godhome"]
     BrokenVesselP,
     /// Paintmaster Sheo (Boss)
     /// 
     /// Splits when killing Paintmaster Sheo
+    #[category = "Godhome"]
     SheoPaintmaster,
     /// Paintmaster Sheo (Pantheon)
     /// 
     /// Splits after killing Paintmaster Sheo in Pantheon 2 or Pantheon 5
+    #[category = "Godhome"]
     SheoPaintmasterP,
     /// Pantheon 2 (Trial)
     /// 
     /// Splits when beating the Pantheon of the Artist
+    #[category = "Godhome"]
     Pantheon2,
     /// Hive Knight (Pantheon)
     /// 
     /// Splits after killing Hive Knight in Pantheon 3 or Pantheon 5
+    #[category = "Godhome"]
     HiveKnightP,
     /// Elder Hu (Pantheon)
     /// 
     /// Splits after killing Elder Hu in Pantheon 3 or Pantheon 5
+    #[category = "Godhome"]
     ElderHuP,
     /// Collector (Pantheon)
     /// 
     /// Splits after killing The Collector in Pantheon 3 or Pantheon 5
+    #[category = "Godhome"]
     CollectorP,
     /// God Tamer (Pantheon)
     /// 
     /// Splits after killing God Tamer in Pantheon 3 or Pantheon 5
+    #[category = "Godhome"]
     GodTamerP,
     /// Troupe Master Grimm (Pantheon)
     /// 
     /// Splits after killing Troupe Master Grimm in Pantheon 3 or Pantheon 5
+    #[category = "Godhome"]
     TroupeMasterGrimmP,
     /// Galien (Pantheon)
     /// 
     /// Splits after killing Galien in Pantheon 3 or Pantheon 5
+    #[category = "Godhome"]
     GalienP,
     /// Grey Prince Zote (Pantheon)
     /// 
     /// Splits after killing Grey Prince Zote in Pantheon 3 or Pantheon 5
+    #[category = "Godhome"]
     GreyPrinceZoteP,
     /// Uumuu (Pantheon)
     /// 
     /// Splits after killing Uumuu in Pantheon 3 or Pantheon 5
+    #[category = "Godhome"]
     UumuuP,
     /// Hornet 2 (Pantheon)
     /// 
     /// Splits after killing Hornet Sentinel in Pantheon 3 or Pantheon 5
+    #[category = "Godhome"]
     Hornet2P,
     /// Great Nailsage Sly (Boss)
     /// 
     /// Splits when killing Great Nailsage Sly
+    #[category = "Godhome"]
     SlyNailsage,
     /// Great Nailsage Sly (Pantheon)
     /// 
     /// Splits after killing Great Nailsage Sly in Pantheon 3 or Pantheon 5
+    #[category = "Godhome"]
     SlyP,
     /// Pantheon 3 (Trial)
     /// 
     /// Splits when beating the Pantheon of the Sage
+    #[category = "Godhome"]
     Pantheon3,
     /// Enraged Guardian (Pantheon)
     /// 
     /// Splits after killing Enraged Guardian in Pantheon 4 or Pantheon 5
+    #[category = "Godhome"]
     EnragedGuardianP,
     /// Lost Kin (Pantheon)
     /// 
     /// Splits after killing Lost Kin in Pantheon 4 or Pantheon 5
+    #[category = "Godhome"]
     LostKinP,
     /// No Eyes (Pantheon)
     /// 
     /// Splits after killing No Eyes in Pantheon 4 or Pantheon 5
+    #[category = "Godhome"]
     NoEyesP,
     /// Traitor Lord (Pantheon)
     /// 
     /// Splits after killing Traitor Lord in Pantheon 4 or Pantheon 5
+    #[category = "Godhome"]
     TraitorLordP,
     /// White Defender (Pantheon)
     /// 
     /// Splits after killing White Defender in Pantheon 4 or Pantheon 5
+    #[category = "Godhome"]
     WhiteDefenderP,
     /// Failed Champion (Pantheon)
     /// 
     /// Splits after killing Failed Champion in Pantheon 4 or Pantheon 5
+    #[category = "Godhome"]
     FailedChampionP,
     /// Markoth (Pantheon)
     /// 
     /// Splits after killing Markoth in Pantheon 4 or Pantheon 5
+    #[category = "Godhome"]
     MarkothP,
     /// Watcher Knights (Pantheon)
     /// 
     /// Splits after killing Watcher Knights in Pantheon 4 or Pantheon 5
+    #[category = "Godhome"]
     WatcherKnightsP,
     /// Soul Tyrant (Pantheon)
     /// 
     /// Splits after killing Soul Tyrant in Pantheon 4 or Pantheon 5
+    #[category = "Godhome"]
     SoulTyrantP,
     /// Pure Vessel (Boss)
     /// 
     /// Splits when killing Pure Vessel
+    #[category = "Godhome"]
     PureVessel,
     /// Pure Vessel (Pantheon)
     /// 
     /// Splits after killing Pure Vessel in Pantheon 4 or Pantheon 5
+    #[category = "Godhome"]
     PureVesselP,
     /// Pantheon 4 (Trial)
     /// 
     /// Splits when beating the Pantheon of the Knight
+    #[category = "Godhome"]
     Pantheon4,
     /// Pantheon 5 (Transition)
     /// 
     /// Splits on entry to Pantheon 5
+    #[category = "Godhome"]
     Pantheon5Entry,
     /// Winged Nosk (Pantheon)
     /// 
     /// Splits after killing Winged Nosk in Pantheon 5
+    #[category = "Godhome"]
     NoskHornetP,
     /// Nightmare King Grimm (Pantheon)
     /// 
     /// Splits after killing Nightmare King Grimm in Pantheon 5
+    #[category = "Godhome"]
     NightmareKingGrimmP,
     /// Absolute Radiance (Pantheon)
     /// 
     /// Splits after killing Absolute Radiance in Pantheon 5
+    #[category = "Godhome"]
     RadianceP,
     /// Pantheon 5 (Trial)
     /// 
     /// Splits when beating the Pantheon of Hallownest
+    #[category = "Godhome"]
     Pantheon5,
     // endregion: Godhome
 }
 
-impl StoreWidget for Split {
-    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
-        let new_s = options_str(self);
-        if settings_map.get(key).is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s)) {
-            return false;
+#[derive(Clone, Default, SetHeadingLevel)]
+#[non_exhaustive]
+pub struct SplitArgs {
+    pub heading_level: u32,
+}
+
+/// A Split in the settings GUI, as a picker for the kind of split and settings for the data it holds.
+///
+/// It keeps the categories its picker added at its key, and the data settings it registered there,
+/// so as an UglyList item, only the split moves between indices.
+#[derive(Clone)]
+pub struct SplitWidget {
+    pub split: Split,
+    picker: UglyPicker<Split>,
+    /// The data settings registered at its key so far
+    registered: BTreeSet<DataSettings>,
+}
+
+impl From<Split> for SplitWidget {
+    /// A widget for the split that hasn't registered anything yet
+    fn from(split: Split) -> SplitWidget {
+        SplitWidget { picker: UglyPicker::new(split.clone()), split, registered: BTreeSet::new() }
+    }
+}
+
+impl ListItemValue for SplitWidget {
+    fn set_value_from(&mut self, other: &SplitWidget) {
+        self.split = other.split.clone();
+    }
+}
+
+impl Widget for SplitWidget {
+    type Args = SplitArgs;
+
+    fn register(key: &str, description: &str, args: Self::Args) -> Self {
+        let picker = UglyPicker::<Split>::register(key, description, picker_args(&args));
+        let mut v = SplitWidget { split: Split::default(), picker, registered: BTreeSet::new() };
+        v.update_from(&asr::settings::Map::load(), key, args);
+        v
+    }
+
+    fn update_from(&mut self, settings_map: &asr::settings::Map, key: &str, args: Self::Args) {
+        let s = settings_map.get(key).and_then(|v| v.get_string());
+        let mut split = match s.as_deref().map(Split::from_str) {
+            Some(Ok(split)) => split,
+            Some(Err(_)) => return,
+            None => Split::default(),
+        };
+        self.picker.value = split.clone();
+        self.picker.update_from(settings_map, key, picker_args(&args));
+        let picked = options_str(&self.picker.value) != options_str(&split);
+        if picked {
+            split = self.picker.value.clone();
+        }
+        self.register_data_settings(key, &split, &args);
+        if picked || !s.is_some_and(|s| s.starts_with('{')) {
+            // the picker only holds the kind of split, the data is in separate settings
+            match &mut split {
+                Split::EnterMapZone(z) | Split::LeaveMapZone(z) => {
+                    z.update_from(settings_map, &format!("{}_map_zone", key), ());
+                }
+                Split::SceneTransition { old, new } => {
+                    old.update_from(settings_map, &format!("{}_old_scene", key), ());
+                    new.update_from(settings_map, &format!("{}_new_scene", key), ());
+                }
+                Split::PlayerDataInt { field, value } => {
+                    update_field_from(field, settings_map, &format!("{}_int_field", key));
+                    let mut n = UglyNumber(*value as i64);
+                    n.update_from(settings_map, &format!("{}_int_value", key), value_args(&args));
+                    *value = n.0.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+                }
+                Split::PlayerDataBool { field } => {
+                    update_field_from(field, settings_map, &format!("{}_bool_field", key));
+                }
+                _ => (),
+            }
+        }
+        self.split = split;
+    }
+}
+
+impl SplitWidget {
+    /// Registers the settings for the data that the split holds, unless they've been registered at the key already,
+    /// so that only the items with such a split have them.
+    /// They come right after the picker when the item is registered,
+    /// but choosing a split that needs them later registers them after everything registered before.
+    fn register_data_settings(&mut self, key: &str, split: &Split, args: &SplitArgs) {
+        let Some(data) = DataSettings::of(split) else { return; };
+        if !self.registered.insert(data) {
+            return;
+        }
+        match data {
+            DataSettings::MapZone => {
+                let key_map_zone = format!("{}_map_zone", key);
+                MapZone::register(&key_map_zone, "Map Zone", ());
+                set_tooltip(&key_map_zone, "Only used by Enter Map Zone and Leave Map Zone splits");
+            }
+            DataSettings::Scenes => {
+                let key_old_scene = format!("{}_old_scene", key);
+                ScenePattern::register(&key_old_scene, "From Scene", ());
+                set_tooltip(&key_old_scene, "Only used by Scene Transition splits");
+                let key_new_scene = format!("{}_new_scene", key);
+                ScenePattern::register(&key_new_scene, "To Scene", ());
+                set_tooltip(&key_new_scene, "Only used by Scene Transition splits");
+            }
+            DataSettings::PlayerDataInt => {
+                let key_int_field = format!("{}_int_field", key);
+                register_field_settings(&key_int_field, "PlayerData Int Field", "Int", PLAYER_DATA_INT_FIELDS, args);
+                let key_int_value = format!("{}_int_value", key);
+                UglyNumber::register(&key_int_value, "PlayerData Int Value", value_args(args));
+            }
+            DataSettings::PlayerDataBool => {
+                let key_bool_field = format!("{}_bool_field", key);
+                register_field_settings(&key_bool_field, "PlayerData Bool Field", "Bool", PLAYER_DATA_BOOL_FIELDS, args);
+            }
         }
-        settings_map.insert(key, new_s);
-        true
     }
 }
 
-impl ToString for Split {
-    fn to_string(&self) -> String {
-        serde_json::to_value(self).unwrap_or_default().as_str().unwrap_or_default().to_string()
+/// The settings for the data of a split, besides the picker for the kind of split
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum DataSettings {
+    /// `_map_zone`
+    MapZone,
+    /// `_old_scene` and `_new_scene`
+    Scenes,
+    /// `_int_field` and `_int_value`
+    PlayerDataInt,
+    /// `_bool_field`
+    PlayerDataBool,
+}
+
+impl DataSettings {
+    /// The data settings that the split needs, if any
+    fn of(split: &Split) -> Option<DataSettings> {
+        match split {
+            Split::EnterMapZone(_) | Split::LeaveMapZone(_) => Some(DataSettings::MapZone),
+            Split::SceneTransition { .. } => Some(DataSettings::Scenes),
+            Split::PlayerDataInt { .. } => Some(DataSettings::PlayerDataInt),
+            Split::PlayerDataBool { .. } => Some(DataSettings::PlayerDataBool),
+            _ => None,
+        }
+    }
+}
+
+impl StoreWidget for SplitWidget {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let a = UglyPicker::new(self.split.clone()).insert_into(settings_map, key);
+        let b = match &self.split {
+            Split::EnterMapZone(z) | Split::LeaveMapZone(z) => z.insert_into(settings_map, &format!("{}_map_zone", key)),
+            Split::SceneTransition { old, new } => {
+                let old_changed = old.insert_into(settings_map, &format!("{}_old_scene", key));
+                let new_changed = new.insert_into(settings_map, &format!("{}_new_scene", key));
+                old_changed || new_changed
+            }
+            Split::PlayerDataInt { field, value } => {
+                let field_changed = insert_field_into(field, settings_map, &format!("{}_int_field", key), "Int");
+                let value_changed = UglyNumber(*value as i64).insert_into(settings_map, &format!("{}_int_value", key));
+                field_changed || value_changed
+            }
+            Split::PlayerDataBool { field } => insert_field_into(field, settings_map, &format!("{}_bool_field", key), "Bool"),
+            _ => false,
+        };
+        a || b
+    }
+}
+
+fn picker_args(args: &SplitArgs) -> UglyPickerArgs {
+    let mut picker_args = UglyPickerArgs::default();
+    picker_args.set_heading_level(args.heading_level);
+    picker_args
+}
+
+fn value_args(args: &SplitArgs) -> UglyNumberArgs {
+    let mut value_args = UglyNumberArgs::default();
+    value_args.set_heading_level(args.heading_level);
+    value_args
+}
+
+/// Registers a PlayerData field name, as text that can be any field since it's read by name,
+/// and a choice of suggested fields under it, that sets the text when one is chosen
+fn register_field_settings(key: &str, description: &str, kind: &str, suggestions: &[&str], args: &SplitArgs) {
+    let mut text_args = UglyTextArgs::default();
+    text_args.set_heading_level(args.heading_level);
+    let field = UglyText::register(key, description, text_args);
+    set_tooltip(key, &field_tooltip(kind, &field.0));
+    let key_suggestion = format!("{}_suggestion", key);
+    add_choice(&key_suggestion, "Suggested Field", "");
+    add_choice_option(&key_suggestion, "", "None");
+    for f in suggestions {
+        add_choice_option(&key_suggestion, f, f);
+    }
+    set_tooltip(&key_suggestion, "Sets the field above to one of these, or to any other field by editing the settings outside of the settings GUI");
+}
+
+fn field_tooltip(kind: &str, field: &str) -> String {
+    if field.is_empty() {
+        format!("Only used by PlayerData {} splits, no field is set", kind)
+    } else {
+        format!("Only used by PlayerData {} splits, the field is {}", kind, field)
+    }
+}
+
+fn update_field_from(field: &mut String, settings_map: &asr::settings::Map, key: &str) {
+    if let Some(s) = settings_map.get(key).and_then(|v| v.get_string()) {
+        *field = s;
+    }
+    // a suggestion replaces the text once, and is cleared again when the field is stored
+    if let Some(s) = settings_map.get(&format!("{}_suggestion", key)).and_then(|v| v.get_string()).filter(|s| !s.is_empty()) {
+        *field = s;
+    }
+}
+
+fn insert_field_into(field: &str, settings_map: &asr::settings::Map, key: &str, kind: &str) -> bool {
+    let suggestion_changed = UglyText::default().insert_into(settings_map, &format!("{}_suggestion", key));
+    let field_changed = UglyText(field.to_string()).insert_into(settings_map, key);
+    if field_changed {
+        set_tooltip(key, &field_tooltip(kind, field));
+    }
+    suggestion_changed || field_changed
+}
+
+/// An item in the list of splits, with the settings that belong to that item besides the split itself
+#[derive(Clone, Widget, StoreWidget)]
+pub struct SplitEntry {
+    /// Split
+    pub split: SplitWidget,
+    /// Name
+    /// 
+    /// The name of the segment ending with this split in the exports, instead of the one from the last import.
+    /// Set it by editing the settings outside of the settings GUI.
+    pub name: UglyText,
+    /// Debounce
+    /// 
+    /// The milliseconds of game time after the previous split before this split can split, or 0 to split right away
+    pub debounce: UglyNumber,
+    /// Enabled
+    /// 
+    /// When this is off, the split is skipped as soon as it comes up instead of splitting,
+    /// or for the first and last splits, left to be done manually
+    #[default = true]
+    pub enabled: bool,
+}
+
+impl From<Split> for SplitEntry {
+    /// An entry for the split with no name, no debounce, and enabled
+    fn from(split: Split) -> SplitEntry {
+        SplitEntry { split: SplitWidget::from(split), name: UglyText::default(), debounce: UglyNumber(0), enabled: true }
+    }
+}
+
+impl SplitEntry {
+    pub fn options(&self) -> SplitOptions {
+        SplitOptions { enabled: self.enabled, debounce: Duration::from_millis(self.debounce.0.max(0) as u64) }
+    }
+}
+
+/// The settings of an entry in the list of splits that the autosplitter uses while running, besides the split
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SplitOptions {
+    pub enabled: bool,
+    /// The game time after the previous split before this split can split
+    pub debounce: Duration,
+}
+
+impl Default for SplitOptions {
+    fn default() -> SplitOptions {
+        SplitOptions { enabled: true, debounce: Duration::ZERO }
+    }
+}
+
+impl fmt::Display for Split {
+    /// The name of the split, or a JSON object for splits that hold data
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let v = serde_json::to_value(self).unwrap_or_default();
+        match v.as_str() {
+            Some(s) => f.write_str(s),
+            None => write!(f, "{}", v),
+        }
     }
 }
 
 impl FromStr for Split {
     type Err = serde_json::Error;
     fn from_str(s: &str) -> Result<Split, serde_json::Error> {
-        serde_json::value::from_value(serde_json::Value::String(s.to_string()))
+        // splits saved under a key that has been renamed since
+        if let Some(new) = renamed_split(s) {
+            return Split::from_str(&new);
+        }
+        if s.starts_with('{') {
+            serde_json::from_str(s)
+        } else {
+            // splits that hold data can also be named alone, with their data set to defaults
+            serde_json::value::from_value(serde_json::Value::String(s.to_string()))
+                .or_else(|e| options_value(s).ok_or(e))
+        }
     }
 }
 
@@ -2517,6 +3462,14 @@ impl Split {
     pub fn from_settings_split<S: Settings>(s: S) -> Option<Split> {
         Split::from_settings_str(s.dict_get("Split").unwrap_or(s))
     }
+    /// The description of the split without the qualifier in parentheses at the end
+    pub fn default_segment_name(&self) -> String {
+        let description = options_description(self);
+        match description.rsplit_once(" (") {
+            Some((name, qualifier)) if qualifier.ends_with(')') => name.to_string(),
+            _ => description.to_string(),
+        }
+    }
 }
 
 pub fn transition_splits(s: &Split, p: &Pair<&str>, prc: &Process, g: &GameManagerFinder, pds: &mut PlayerDataStore) -> bool {
@@ -2548,6 +3501,10 @@ pub fn transition_splits(s: &Split, p: &Pair<&str>, prc: &Process, g: &GameManag
         Split::MenuDreamer3 => 3 <= pds.guardians_defeated(prc, g) && is_menu(p.current),
         // endregion: Dreamers
 
+        // region: Scene Transitions
+        Split::SceneTransition { old, new } => old.matches(p.old) && new.matches(p.current),
+        // endregion: Scene Transitions
+
         // region: Dirtmouth
         Split::KingsPass => p.old == "Tutorial_01" && p.current == "Town",
         Split::EnterDirtmouth => p.current == "Town" && p.current != p.old,
@@ -2577,7 +3534,7 @@ pub fn transition_splits(s: &Split, p: &Pair<&str>, prc: &Process, g: &GameManag
         Split::MegaMossChargerTrans => pds.mega_moss_charger_defeated(prc, g) && p.current != p.old,
         // endregion: Greenpath
         // region: Fungal
-        Split::FungalWastesEntry => starts_with_any(p.current, FUNGAL_WASTES_ENTRY_SCENES) && p.current != p.old,
+        Split::FungalWastesEntry => Area::FungalWastes.is_entry_transition(p),
         Split::ElderHuTrans => pds.killed_ghost_hu(prc, g) && p.current != p.old,
         Split::MenuDashmaster => pds.got_charm_31(prc, g) && is_menu(p.current),
         Split::TransClaw => pds.has_wall_jump(prc, g) && p.current != p.old,
@@ -2590,7 +3547,7 @@ pub fn transition_splits(s: &Split, p: &Pair<&str>, prc: &Process, g: &GameManag
         //       or only the rooms that the area text can appear in?
         // region: Resting Grounds
         Split::BlueLake => p.current.starts_with("Crossroads_50") && !p.old.starts_with("Crossroads_50"), // blue lake is Crossroads_50
-        Split::EnterAnyDream => p.current.starts_with("Dream_") && p.current != p.old,
+        Split::EnterAnyDream => is_dream_scene(p.current) && p.current != p.old,
         Split::DreamNailExit => p.old == "Dream_Nailcollection" && p.current == "RestingGrounds_07",
         Split::MenuDreamNail => pds.has_dream_nail(prc, g) && is_menu(p.current),
         Split::MenuDreamGate => pds.has_dream_gate(prc, g) && is_menu(p.current),
@@ -2612,14 +3569,14 @@ pub fn transition_splits(s: &Split, p: &Pair<&str>, prc: &Process, g: &GameManag
         Split::TransCollector => pds.collector_defeated(prc, g) && p.current != p.old,
         // endregion: City
         // region: Peak
-        Split::CrystalPeakEntry => starts_with_any(p.current, CRYSTAL_PEAK_ENTRY_SCENES) && p.current != p.old,
+        Split::CrystalPeakEntry => Area::CrystalPeak.is_entry_transition(p),
         Split::MenuSlyKey => is_menu(p.current) && p.old == "Mines_11",
         Split::EnterCrown => p.current == "Mines_23" && p.current != p.old,
         Split::TransDescendingDark => 2 <= pds.get_quake_level(prc, g) && p.current != p.old,
         Split::CrystalMoundExit => p.old.starts_with("Mines_35") && p.current != p.old,
         // endregion: Peak
         // region: Waterways
-        Split::WaterwaysEntry => starts_with_any(p.current, WATERWAYS_ENTRY_SCENES) && p.current != p.old,
+        Split::WaterwaysEntry => Area::RoyalWaterways.is_entry_transition(p),
         Split::DungDefenderExit => p.old == "Waterways_05" && p.current == "Abyss_01",
         Split::TransTear => pds.has_acid_armour(prc, g) && p.current != p.old,
         Split::MenuIsmasTear => pds.has_acid_armour(prc, g) && is_menu(p.current),
@@ -2670,17 +3627,17 @@ pub fn transition_splits(s: &Split, p: &Pair<&str>, prc: &Process, g: &GameManag
         Split::ColosseumGoldExit => pds.colosseum_bronze_completed(prc, g) && !p.current.starts_with("Room_Colosseum_Gold"),
         // endregion: Colosseum
         // region: Fog Canyon
-        Split::FogCanyonEntry => starts_with_any(p.current, FOG_CANYON_ENTRY_SCENES) && p.current != p.old,
+        Split::FogCanyonEntry => Area::FogCanyon.is_entry_transition(p),
         Split::TeachersArchive => p.current.starts_with("Fungus3_archive") && !p.old.starts_with("Fungus3_archive"),
         // endregion: Fog Canyon
         // region: Queen's Gardens
-        Split::QueensGardensEntry => starts_with_any(p.current, QUEENS_GARDENS_ENTRY_SCENES) && p.current != p.old,
+        Split::QueensGardensEntry => Area::QueensGardens.is_entry_transition(p),
         Split::QueensGardensPostArenaTransition => p.current.starts_with("Fungus3_13") && p.current != p.old,
         // Fungus1_23 is the first frogs room in QG, even though QG usually uses Fungus3, and GP usually uses Fungus1
         Split::QueensGardensFrogsTrans => p.current.starts_with("Fungus1_23") && p.current != p.old,
         // endregion: Queen's Gardens
         // region: Deepnest
-        Split::EnterDeepnest => starts_with_any(p.current, DEEPNEST_ENTRY_SCENES) && p.current != p.old,
+        Split::EnterDeepnest => Area::Deepnest.is_entry_transition(p),
         Split::EnterNosk => p.current.starts_with("Deepnest_32") && p.current != p.old,
         // endregion: Deepnest
         // region: Godhome
@@ -2772,7 +3729,7 @@ pub fn continuous_splits(s: &Split, p: &Process, g: &GameManagerFinder, pds: &mu
         Split::Dreamer2 => g.guardians_defeated(p).is_some_and(|d| 2 <= d),
         Split::Dreamer3 => g.guardians_defeated(p).is_some_and(|d| 3 <= d),
         Split::MenuDreamer3 => { pds.guardians_defeated(p, g); false },
-        // Old Dreamer Timings, mark deprecated or whatever
+        // Old Dreamer Timings, deprecated
         Split::LurienDreamer => g.lurien_defeated(p).is_some_and(|d| d),
         Split::MonomonDreamer => g.monomon_defeated(p).is_some_and(|d| d),
         Split::HegemolDreamer => g.hegemol_defeated(p).is_some_and(|d| d),
@@ -2965,8 +3922,8 @@ pub fn continuous_splits(s: &Split, p: &Process, g: &GameManagerFinder, pds: &mu
                                && g.fragile_strength_unbreakable(p).is_some_and(|u| u),
         // Grimmchild / Carefree Melody
         Split::Grimmchild => g.got_charm_40(p).is_some_and(|c| c) && g.grimm_child_level(p).is_some_and(|l| l <= 4),
-        Split::Grimmchild2 => g.grimm_child_level(p).is_some_and(|l| 2 <= l && l <= 4),
-        Split::Grimmchild3 => g.grimm_child_level(p).is_some_and(|l| 3 <= l && l <= 4),
+        Split::Grimmchild2 => g.grimm_child_level(p).is_some_and(|l| (2..=4).contains(&l)),
+        Split::Grimmchild3 => g.grimm_child_level(p).is_some_and(|l| (3..=4).contains(&l)),
         Split::Grimmchild4 => g.grimm_child_level(p).is_some_and(|l| l == 4),
         Split::CarefreeMelody => g.got_charm_40(p).is_some_and(|c| c) && g.grimm_child_level(p).is_some_and(|l| l == 5),
         Split::Flame1 => g.flames_collected(p).is_some_and(|f| 1 <= f),
@@ -3138,6 +4095,19 @@ pub fn continuous_splits(s: &Split, p: &Process, g: &GameManagerFinder, pds: &mu
         Split::OnObtainGhostJoni => pds.incremented_dream_orbs(p, g) && g.get_scene_name(p).is_some_and(|s| s == "Cliffs_05"),
         // TODO: resolve possible confounding essence sources for Cloth, Vespa, and Revek
         // endregion: Essence, Trees, and Ghosts
+        // region: Completion
+        Split::Completion100 => pds.completion_percentage(p, g).is_some_and(|c| 100 <= c),
+        Split::Completion106 => pds.completion_percentage(p, g).is_some_and(|c| 106 <= c),
+        Split::Completion112 => pds.completion_percentage(p, g).is_some_and(|c| 112 <= c),
+        // endregion: Completion
+        // region: Map Zones
+        Split::EnterMapZone(z) => pds.entered_map_zone(p, g, *z),
+        Split::LeaveMapZone(z) => pds.left_map_zone(p, g, *z),
+        // endregion: Map Zones
+        // region: PlayerData
+        Split::PlayerDataInt { field, value } => g.player_data_i32_by_name(p, field).is_some_and(|i| *value <= i),
+        Split::PlayerDataBool { field } => g.player_data_bool_by_name(p, field).is_some_and(|b| b),
+        // endregion: PlayerData
         // region: Dirtmouth
         Split::Dirtmouth => g.visited_dirtmouth(p).is_some_and(|v| v),
         Split::ElderbugFlower => g.elderbug_gave_flower(p).is_some_and(|g| g),
@@ -3349,8 +4319,62 @@ pub fn default_splits() -> Vec<Split> {
          Split::EndingSplit]
 }
 
+/// Suggested segment names for the splits after the one that starts the timer,
+/// with names that would otherwise be repeated numbered in order, like `Mask Shard 1`, `Mask Shard 2`,
+/// skipping any numbers that other segments already have
+pub fn default_segment_names(s: &[Split]) -> Vec<String> {
+    let names: Vec<String> = s.iter().skip(1).map(Split::default_segment_name).collect();
+    let mut used: BTreeSet<String> = names.iter().cloned().collect();
+    let mut counts: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    for name in names.iter() {
+        counts.entry(name).or_default().1 += 1;
+    }
+    names.iter().map(|name| {
+        let (i, n) = counts.get_mut(name.as_str()).unwrap();
+        if *n <= 1 {
+            return name.clone();
+        }
+        let numbered = loop {
+            *i += 1;
+            let numbered = format!("{} {}", name, i);
+            if !used.contains(&numbered) {
+                break numbered;
+            }
+        };
+        used.insert(numbered.clone());
+        numbered
+    }).collect()
+}
+
 pub fn auto_reset_safe(s: &[Split]) -> bool {
     s.first() == Some(&Split::StartNewGame)
     && !s[1..].contains(&Split::StartNewGame)
     && !s[0..(s.len()-1)].contains(&Split::EndingSplit)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lss::LssSplits;
+
+    #[test]
+    fn default_segment_names_numbering() {
+        assert_eq!(Split::Ore1.default_segment_name(), "Pale Ore 1");
+        assert_eq!(Split::OnObtainPaleOre.default_segment_name(), "Pale Ore");
+        assert!(default_segment_names(&[Split::StartNewGame]).is_empty());
+        assert_eq!(default_segment_names(&[Split::StartNewGame, Split::Ore1, Split::Ore2]), ["Pale Ore 1", "Pale Ore 2"]);
+        // repeated names are numbered, skipping the numbered names that are already used
+        assert_eq!(
+            default_segment_names(&[Split::StartNewGame, Split::OnObtainPaleOre, Split::Ore1, Split::OnObtainPaleOre]),
+            ["Pale Ore 2", "Pale Ore 1", "Pale Ore 3"],
+        );
+    }
+
+    #[test]
+    fn default_segment_names_lss() {
+        let splits = vec![Split::StartNewGame, Split::OnObtainPaleOre, Split::OnObtainPaleOre, Split::EndingSplit];
+        let lss_splits = LssSplits::from_splits(splits);
+        assert!(lss_splits.is_valid());
+        assert_eq!(lss_splits.segment_names[..2], ["Pale Ore 1", "Pale Ore 2"]);
+    }
+}